@@ -268,6 +268,12 @@ fn testnet_genesis(
 					(get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"), CurrencyId::Native),
 				]
 			},
+			init_categories: ["defi", "wallet", "nft", "dao", "gaming", "infrastructure"]
+				.iter()
+				.map(|category| category.as_bytes().to_vec())
+				.collect(),
+			// The dev and local chains have no registrar to verify owners.
+			require_identity: false,
 		},
 		phragmen_election: parachain_template_runtime::PhragmenElectionConfig {
 			// configure all members to have an initial 'stash' backing, or elect them
@@ -282,8 +288,10 @@ fn testnet_genesis(
 		},
 		council: parachain_template_runtime::CouncilConfig::default(),
 		treasury: Default::default(),
+		vesting: Default::default(),
 		minting_module: parachain_template_runtime::MintingModuleConfig {
-			init_mint: 5000 * UNIT
+			init_mint: 5000 * UNIT,
+			init_mint_to: Vec::new(),
 		}
 	}
 }
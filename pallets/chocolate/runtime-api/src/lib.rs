@@ -0,0 +1,26 @@
+//! Runtime API for the chocolate pallet. Lets frontends list projects without scanning storage.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use chocolate_primitives::projects::{IdentityStatus, ProjectID, ProjectRating, Status};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Queries over the indices kept by the chocolate pallet.
+	///
+	/// Paginated queries return at most `limit` ids, starting after the `start_after` cursor.
+	/// Pass the last id of a page as the cursor of the next one.
+	pub trait ChocolateApi {
+		/// Ids of the projects in a category.
+		fn projects_by_category(category: Vec<u8>, start_after: Option<ProjectID>, limit: u32) -> Vec<ProjectID>;
+		/// Ids of the open projects carrying a tag.
+		fn projects_by_tag(tag: Vec<u8>, start_after: Option<ProjectID>, limit: u32) -> Vec<ProjectID>;
+		/// Ids of the projects with a status.
+		fn projects_by_status(status: Status, start_after: Option<ProjectID>, limit: u32) -> Vec<ProjectID>;
+		/// Registered categories.
+		fn categories(start_after: Option<Vec<u8>>, limit: u32) -> Vec<Vec<u8>>;
+		/// Average ratings of a project, overall and per rating dimension.
+		fn project_rating(project_id: ProjectID) -> Option<ProjectRating>;
+		/// How far the identity of a project's owner has been verified.
+		fn owner_identity(project_id: ProjectID) -> Option<IdentityStatus>;
+	}
+}
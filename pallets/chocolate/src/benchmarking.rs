@@ -3,31 +3,510 @@
 use super::*;
 #[allow(unused)]
 use crate::Pallet as Chocolate;
-use chocolate_projects::Review;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, OnUnbalanced};
-use frame_system::RawOrigin;
-// calls do_something multiple times 0..100 inputs verifying it got stored each time
-benchmarks! { // comment for now, do_something is gone, but it's legacy remains
+use crate::{
+	constants::project::{METADATA, REVS},
+	offchain::FlagPayload,
+};
+use chocolate_primitives::{projects::*, users::UserIO};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::{EnsureOrigin, Get},
+};
+use frame_system::{
+	offchain::{AppCrypto, SignedPayload},
+	RawOrigin,
+};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use sp_runtime::{
+	traits::{Hash, IdentifyAccount, Saturating},
+	RuntimeAppPublic,
+};
+use sp_std::vec::Vec;
+
+const SEED: u32 = 0;
+/// Upper bound on review responses when benchmarking `close_project`.
+const MAX_RESPONSES: u32 = 100;
+
+fn bounded<T: Config>(bytes: &[u8]) -> BoundedVecOf<u8, T> {
+	bytes.to_vec().try_into().expect("Should be within string limit")
+}
+
+/// Register the account as a user, with enough native and collateral currency for any call.
+fn funded_user<T: Config>(who: T::AccountId) -> T::AccountId {
+	let amount = T::RewardCap::get()
+		.saturating_add(T::UserCollateral::get())
+		.saturating_mul(100u32.into());
+	assert_ok!(T::Currency::deposit(T::GetNativeCurrencyId::get(), &who, amount));
+	assert_ok!(T::Currency::deposit(T::BenchmarkCollateralCurrencyId::get(), &who, amount));
+	assert_ok!(T::UsersOutlet::get_or_create_default(&who));
+	who
+}
+
+/// `count` distinct tags.
+fn tags<T: Config>(count: u32) -> TagsOf<T> {
+	let tags: Vec<LabelOf<T>> =
+		(0..count).map(|index| bounded::<T>(&[b't', b'a' + (index % 26) as u8])).collect();
+	tags.try_into().expect("Should be within MaxTags")
+}
+
+fn profile<T: Config>() -> ProjectProfileOf<T> {
+	ProjectProfile {
+		name: bounded::<T>(b"Chocolate"),
+		website: bounded::<T>(b"https://chocolate.network"),
+		category: bounded::<T>(b"defi"),
+		token_symbol: Some(bounded::<T>(b"CHOC")),
+		para_id: Some(2000),
+		metadata: bounded::<T>(METADATA[0]),
+	}
+}
+
+/// Create a proposed project owned by the account.
+fn create_project<T: Config>(owner: &T::AccountId) -> ProjectID {
+	<Categories<T>>::insert(bounded::<T>(b"defi"), ());
+	let project_id = <NextProjectIndex<T>>::get().unwrap_or(1);
+	let origin = RawOrigin::Signed(owner.clone());
+	assert_ok!(Chocolate::<T>::create_project(origin.into(), profile::<T>()));
+	project_id
+}
+
+/// Create a proposed review of the project by the account.
+fn create_review<T: Config>(reviewer: &T::AccountId, project_id: ProjectID) -> ReviewID {
+	let review_id = <NextReviewIndex<T>>::get().unwrap_or(1);
+	assert_ok!(Chocolate::<T>::create_review(
+		RawOrigin::Signed(reviewer.clone()).into(),
+		(3, bounded::<T>(REVS[0].1)),
+		[3; NUMBER_OF_DIMENSIONS],
+		project_id,
+		T::BenchmarkCollateralCurrencyId::get(),
+	));
+	review_id
+}
+
+/// An accepted review of a new project. Returns the project owner and the review id.
+fn accepted_review<T: Config>() -> (T::AccountId, ReviewID) {
+	let owner = funded_user::<T>(account("owner", 0, SEED));
+	let reviewer = funded_user::<T>(account("reviewer", 0, SEED));
+	let project_id = create_project::<T>(&owner);
+	let review_id = create_review::<T>(&reviewer, project_id);
+	let origin = T::ApprovedOrigin::successful_origin();
+	assert_ok!(Chocolate::<T>::accept_review(origin, reviewer, project_id));
+	(owner, review_id)
+}
+
+/// Set the helpful votes on a review so the next helpful vote earns its author a rank point.
+fn one_vote_from_point<T: Config>(review_id: ReviewID) {
+	<Reviews<T>>::mutate(review_id, |review| {
+		if let Some(review) = review {
+			review.helpful_votes = T::VotesPerRankPoint::get().saturating_sub(1);
+		}
+	});
+}
+
+/// A project in the commit phase of a review round, and the reviewer's commitment to the
+/// benchmark review.
+fn review_round<T: Config>(owner: &T::AccountId, reviewer: &T::AccountId) -> (ProjectID, T::Hash) {
+	let project_id = create_project::<T>(owner);
+	let period = T::MaxRoundPeriod::get();
+	let origin = RawOrigin::Signed(owner.clone());
+	assert_ok!(Chocolate::<T>::start_review_round(origin.into(), project_id, period, period));
+	let content = bounded::<T>(REVS[0].1);
+	let scores = [3u8; NUMBER_OF_DIMENSIONS];
+	let commitment = T::Hashing::hash_of(&(reviewer, project_id, 3u8, scores, content, [0u8; 32]));
+	(project_id, commitment)
+}
+
+benchmarks! {
+	create_project {
+		let caller = funded_user::<T>(whitelisted_caller());
+		<Categories<T>>::insert(bounded::<T>(b"defi"), ());
+		let project_id = <NextProjectIndex<T>>::get().unwrap_or(1);
+	}: _(RawOrigin::Signed(caller.clone()), profile::<T>())
+	verify {
+		let owner = Chocolate::<T>::get_projects(project_id).map(|project| project.owner_id);
+		assert_eq!(owner, Some(caller));
+	}
+
 	create_review {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&owner);
+		let review_id = <NextReviewIndex<T>>::get().unwrap_or(1);
+		let review_meta = (3, bounded::<T>(REVS[0].1));
+		let collateral_currency_id = T::BenchmarkCollateralCurrencyId::get();
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		review_meta,
+		[3; NUMBER_OF_DIMENSIONS],
+		project_id,
+		collateral_currency_id
+	)
+	verify {
+		assert_eq!(<LatestReviews<T>>::get(&caller, project_id), Some(review_id));
+	}
+
+	edit_review {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&owner);
+		let review_id = create_review::<T>(&caller, project_id);
+		let review_meta = (4, bounded::<T>(REVS[1].1));
+	}: _(RawOrigin::Signed(caller), project_id, review_meta, [4; NUMBER_OF_DIMENSIONS])
+	verify {
+		assert_eq!(<Reviews<T>>::get(review_id).map(|review| review.edit_count), Some(1));
+	}
+
+	vote_review {
+		let (_, review_id) = accepted_review::<T>();
+		let caller = funded_user::<T>(whitelisted_caller());
+		one_vote_from_point::<T>(review_id);
+	}: _(RawOrigin::Signed(caller.clone()), review_id, true)
+	verify {
+		assert_eq!(<ReviewVotes<T>>::get(review_id, &caller), Some(true));
+		assert_eq!(<Reviews<T>>::get(review_id).map(|review| review.helpfulness_points), Some(1));
+	}
+
+	respond_to_review {
+		let (owner, review_id) = accepted_review::<T>();
+		// Replace an earlier response.
+		let origin = RawOrigin::Signed(owner.clone());
+		assert_ok!(Chocolate::<T>::respond_to_review(origin.into(), review_id, bounded::<T>(METADATA[0])));
+		let response = bounded::<T>(METADATA[1]);
+	}: _(RawOrigin::Signed(owner), review_id, response.clone())
+	verify {
+		let project_id = <Reviews<T>>::get(review_id).map(|review| review.project_id).unwrap_or(0);
+		let cid = <ReviewResponses<T>>::get(project_id, review_id).map(|(cid, _, _)| cid);
+		assert_eq!(cid, Some(response));
+	}
+
+	remove_review_response {
+		let (owner, review_id) = accepted_review::<T>();
+		let origin = RawOrigin::Signed(owner.clone());
+		assert_ok!(Chocolate::<T>::respond_to_review(origin.into(), review_id, bounded::<T>(METADATA[1])));
+	}: _(RawOrigin::Signed(owner), review_id)
+	verify {
+		let project_id = <Reviews<T>>::get(review_id).map(|review| review.project_id).unwrap_or(0);
+		assert!(!<ReviewResponses<T>>::contains_key(project_id, review_id));
+	}
+
+	retract_vote {
+		let (_, review_id) = accepted_review::<T>();
+		let caller = funded_user::<T>(whitelisted_caller());
+		// Retracting the vote costs its author a rank point.
+		one_vote_from_point::<T>(review_id);
+		let origin = RawOrigin::Signed(caller.clone());
+		assert_ok!(Chocolate::<T>::vote_review(origin.into(), review_id, true));
+	}: _(RawOrigin::Signed(caller.clone()), review_id)
+	verify {
+		assert!(!<ReviewVotes<T>>::contains_key(review_id, &caller));
+		assert_eq!(<Reviews<T>>::get(review_id).map(|review| review.helpfulness_points), Some(0));
+	}
+
+	accept_review {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let reviewer = funded_user::<T>(account("reviewer", 0, SEED));
+		let project_id = create_project::<T>(&owner);
+		let review_id = create_review::<T>(&reviewer, project_id);
+		let origin = T::ApprovedOrigin::successful_origin();
+	}: _<T::Origin>(origin, reviewer, project_id)
+	verify {
+		let status = <Reviews<T>>::get(review_id).map(|review| review.proposal_status.status);
+		assert_eq!(status, Some(Status::Accepted));
+	}
+
+	// worst case slashes the collateral of a malicious review and penalises the reviewer
+	reject_review {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let reviewer = funded_user::<T>(account("reviewer", 0, SEED));
+		let project_id = create_project::<T>(&owner);
+		let review_id = create_review::<T>(&reviewer, project_id);
+		let origin = T::ApprovedOrigin::successful_origin();
+	}: _<T::Origin>(origin, reviewer, project_id, Reason::Malicious)
+	verify {
+		let status = <Reviews<T>>::get(review_id).map(|review| review.proposal_status.status);
+		assert_eq!(status, Some(Status::Rejected));
+	}
+
+	accept_project {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let project_id = create_project::<T>(&owner);
+		T::IdentityVerifier::set_verified(&owner);
+		let origin = T::ApprovedOrigin::successful_origin();
+	}: _<T::Origin>(origin, project_id)
+	verify {
+		let project = Chocolate::<T>::get_projects(project_id);
+		assert_eq!(project.map(|project| project.proposal_status.status), Some(Status::Accepted));
+	}
+
+	update_project_metadata {
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&caller);
+		// A full history drops its oldest entry.
+		for _ in 0..T::MaxMetadataHistory::get() {
+			Chocolate::<T>::archive_metadata(project_id, bounded::<T>(METADATA[0]));
+		}
+		let metadata = bounded::<T>(METADATA[1]);
+	}: _(RawOrigin::Signed(caller), project_id, metadata.clone(), true)
+	verify {
+		let project = Chocolate::<T>::get_projects(project_id);
+		assert_eq!(project.map(|project| project.metadata().clone()), Some(metadata));
+	}
+
+	close_project {
+		let r in 0 .. MAX_RESPONSES;
+		let t in 0 .. T::MaxTags::get();
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&caller);
+		<ProjectTags<T>>::insert(project_id, tags::<T>(t));
+		// Responses are written directly, each with its own depositor.
+		let response = bounded::<T>(METADATA[0]);
+		let deposit = T::DataDepositPerByte::get().saturating_mul((response.len() as u32).into());
+		for review_id in 0..r {
+			let responder = funded_user::<T>(account("responder", review_id, SEED));
+			assert_ok!(T::Currency::reserve(T::GetNativeCurrencyId::get(), &responder, deposit));
+			let entry = (response.clone(), responder, deposit);
+			<ReviewResponses<T>>::insert(project_id, review_id as ReviewID, entry);
+		}
+	}: _(RawOrigin::Signed(caller), project_id, r)
+	verify {
+		let project = Chocolate::<T>::get_projects(project_id);
+		assert_eq!(project.map(|project| project.proposal_status.status), Some(Status::Closed));
+		assert_eq!(<ReviewResponses<T>>::iter_prefix(project_id).count(), 0);
+		assert!(<ProjectsByTag<T>>::iter().all(|(_, id, _)| id != project_id));
+	}
+
+	propose_owner {
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&caller);
+		let new_owner: T::AccountId = account("new_owner", 0, SEED);
+	}: _(RawOrigin::Signed(caller), project_id, new_owner.clone())
+	verify {
+		assert_eq!(<PendingOwners<T>>::get(project_id), Some(new_owner));
+	}
+
+	accept_ownership {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&owner);
+		// Handing over an accepted project checks the new owner's identity.
+		T::IdentityVerifier::set_verified(&owner);
+		T::IdentityVerifier::set_verified(&caller);
+		let approved = T::ApprovedOrigin::successful_origin();
+		assert_ok!(Chocolate::<T>::accept_project(approved, project_id));
+		// The new owner leaves the team.
+		let origin = RawOrigin::Signed(owner.clone());
+		let role = ProjectRole::Admin;
+		assert_ok!(Chocolate::<T>::set_team_member(origin.clone().into(), project_id, caller.clone(), role));
+		assert_ok!(Chocolate::<T>::propose_owner(origin.into(), project_id, caller.clone()));
+	}: _(RawOrigin::Signed(caller.clone()), project_id)
+	verify {
+		let project = Chocolate::<T>::get_projects(project_id);
+		assert_eq!(project.map(|project| project.owner_id), Some(caller));
+	}
+
+	set_team_member {
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&caller);
+		// Fill the team but for the new member.
+		for index in 1..T::MaxTeamMembers::get() {
+			let member: T::AccountId = account("member", index, SEED);
+			let origin = RawOrigin::Signed(caller.clone());
+			let role = ProjectRole::Responder;
+			assert_ok!(Chocolate::<T>::set_team_member(origin.into(), project_id, member, role));
+		}
+		let member: T::AccountId = account("member", 0, SEED);
+	}: _(RawOrigin::Signed(caller), project_id, member.clone(), ProjectRole::Admin)
+	verify {
+		assert_eq!(Chocolate::<T>::team_role(project_id, &member), Some(ProjectRole::Admin));
+	}
+
+	remove_team_member {
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&caller);
+		let team_size = T::MaxTeamMembers::get();
+		for index in 0..team_size {
+			let member: T::AccountId = account("member", index, SEED);
+			let origin = RawOrigin::Signed(caller.clone());
+			let role = ProjectRole::Responder;
+			assert_ok!(Chocolate::<T>::set_team_member(origin.into(), project_id, member, role));
+		}
+		// The last member is found last.
+		let member: T::AccountId = account("member", team_size.saturating_sub(1), SEED);
+	}: _(RawOrigin::Signed(caller), project_id, member.clone())
+	verify {
+		assert_eq!(Chocolate::<T>::team_role(project_id, &member), None);
+	}
+
+	top_up_reward {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&owner);
+		// Funds from a team member are transferred to the owner first.
+		let origin = RawOrigin::Signed(owner);
+		let role = ProjectRole::Maintainer;
+		assert_ok!(Chocolate::<T>::set_team_member(origin.into(), project_id, caller.clone(), role));
+		let amount = T::RewardCap::get() / 2u32.into();
+		<Projects<T>>::mutate(project_id, |project| {
+			if let Some(project) = project {
+				project.reward = project.reward.saturating_sub(amount);
+			}
+		});
+	}: _(RawOrigin::Signed(caller), project_id, amount)
+	verify {
+		let project = Chocolate::<T>::get_projects(project_id);
+		assert_eq!(project.map(|project| project.reward), Some(T::RewardCap::get()));
+	}
+
+	add_category {
+		let origin = T::ApprovedOrigin::successful_origin();
+		let category = bounded::<T>(b"governance");
+	}: _<T::Origin>(origin, category.clone())
+	verify {
+		assert!(<Categories<T>>::contains_key(&category));
+	}
+
+	remove_category {
+		let origin = T::ApprovedOrigin::successful_origin();
+		let category = bounded::<T>(b"governance");
+		<Categories<T>>::insert(&category, ());
+	}: _<T::Origin>(origin, category.clone())
+	verify {
+		assert!(!<Categories<T>>::contains_key(&category));
+	}
+
+	set_project_tags {
+		let t in 0 .. T::MaxTags::get();
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&caller);
+		// The old tags are taken out of the index and the new ones put in.
+		let old_tags = tags::<T>(t);
+		for tag in old_tags.iter() {
+			<ProjectsByTag<T>>::insert(tag, project_id, ());
+		}
+		<ProjectTags<T>>::insert(project_id, old_tags);
+		let tags: Vec<LabelOf<T>> =
+			(0..t).map(|index| bounded::<T>(&[b'n', b'a' + (index % 26) as u8])).collect();
+		let tags: TagsOf<T> = tags.try_into().expect("Should be within MaxTags");
+	}: _(RawOrigin::Signed(caller), project_id, tags.clone())
+	verify {
+		assert_eq!(<ProjectTags<T>>::get(project_id), tags);
+		assert_eq!(<ProjectsByTag<T>>::iter().count(), t as usize);
+	}
+
+	start_review_round {
+		let caller = funded_user::<T>(whitelisted_caller());
+		let project_id = create_project::<T>(&caller);
+		let period = T::MaxRoundPeriod::get();
+	}: _(RawOrigin::Signed(caller), project_id, period, period)
+	verify {
+		assert!(<ReviewRounds<T>>::contains_key(project_id));
+	}
+
+	commit_review {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let caller = funded_user::<T>(whitelisted_caller());
+		let (project_id, commitment) = review_round::<T>(&owner, &caller);
+		let collateral_currency_id = T::BenchmarkCollateralCurrencyId::get();
+	}: _(RawOrigin::Signed(caller.clone()), project_id, commitment, collateral_currency_id)
+	verify {
+		assert!(<ReviewCommits<T>>::contains_key(&caller, project_id));
+	}
+
+	reveal_review {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let caller = funded_user::<T>(whitelisted_caller());
+		let (project_id, commitment) = review_round::<T>(&owner, &caller);
+		let origin = RawOrigin::Signed(caller.clone());
+		let collateral_currency_id = T::BenchmarkCollateralCurrencyId::get();
+		assert_ok!(Chocolate::<T>::commit_review(origin.into(), project_id, commitment, collateral_currency_id));
+		let round = <ReviewRounds<T>>::get(project_id).expect("Round was started");
+		frame_system::Pallet::<T>::set_block_number(round.commit_end.saturating_add(1u32.into()));
+		let review_meta = (3, bounded::<T>(REVS[0].1));
+	}: _(RawOrigin::Signed(caller.clone()), project_id, review_meta, [3; NUMBER_OF_DIMENSIONS], [0; 32])
+	verify {
+		assert!(<LatestReviews<T>>::contains_key(&caller, project_id));
+	}
+
+	forfeit_commit {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let reviewer = funded_user::<T>(account("reviewer", 0, SEED));
+		let caller: T::AccountId = whitelisted_caller();
+		let (project_id, commitment) = review_round::<T>(&owner, &reviewer);
+		let origin = RawOrigin::Signed(reviewer.clone());
+		let collateral_currency_id = T::BenchmarkCollateralCurrencyId::get();
+		assert_ok!(Chocolate::<T>::commit_review(origin.into(), project_id, commitment, collateral_currency_id));
+		let round = <ReviewRounds<T>>::get(project_id).expect("Round was started");
+		frame_system::Pallet::<T>::set_block_number(round.reveal_end.saturating_add(1u32.into()));
+	}: _(RawOrigin::Signed(caller), reviewer.clone(), project_id)
+	verify {
+		assert!(!<ReviewCommits<T>>::contains_key(&reviewer, project_id));
+	}
+
+	add_content_verifier {
+		let origin = T::ApprovedOrigin::successful_origin();
+		let verifier: T::AccountId = account("verifier", 0, SEED);
+	}: _<T::Origin>(origin, verifier.clone())
+	verify {
+		assert!(<ContentVerifiers<T>>::contains_key(&verifier));
+	}
+
+	remove_content_verifier {
+		let origin = T::ApprovedOrigin::successful_origin();
+		let verifier: T::AccountId = account("verifier", 0, SEED);
+		<ContentVerifiers<T>>::insert(&verifier, ());
+	}: _<T::Origin>(origin, verifier.clone())
+	verify {
+		assert!(!<ContentVerifiers<T>>::contains_key(&verifier));
+	}
+
+	flag_content {
+		let owner = funded_user::<T>(account("owner", 0, SEED));
+		let project_id = create_project::<T>(&owner);
+		let key =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(None);
+		let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic = key.into();
+		let public: T::Public = generic.into();
+		<ContentVerifiers<T>>::insert(public.clone().into_account(), ());
+		let content = ContentRef::Project(project_id);
+		let payload = FlagPayload {
+			content: content.clone(),
+			cid: bounded::<T>(METADATA[0]),
+			reason: FlagReason::Malformed,
+			block_number: frame_system::Pallet::<T>::block_number(),
+			public,
+		};
+		let signature = <FlagPayloadOf<T> as SignedPayload<T>>::sign::<T::AuthorityId>(&payload)
+			.expect("Key was just generated");
+	}: _(RawOrigin::None, payload, signature)
+	verify {
+		assert_eq!(<Flags<T>>::get(&content), Some(FlagReason::Malformed));
+	}
+
+	clear_flag {
+		let origin = T::ApprovedOrigin::successful_origin();
+		let content = ContentRef::Project(1);
+		<Flags<T>>::insert(&content, FlagReason::Unavailable);
+	}: _<T::Origin>(origin, content.clone())
+	verify {
+		assert!(!<Flags<T>>::contains_key(&content));
+	}
+
+	note_transfer {
 		let caller: T::AccountId = whitelisted_caller();
-		let s = (b"random").to_vec();
-		let prj_id = 1;
-		let rev = Review{
-			user_id: caller.clone(),
-			proposal_status: Default::default(),
-			content: s.clone(),
-			project_id: prj_id,
-			point_snapshot: 12,};
-		// costs
-		let price = T::Currency::minimum_balance();
-		let collat = T::UserCollateral::get();
-		let imb =  T::Currency::issue(collat);
-		let _ = T::Currency::make_free_balance_be(&caller, imb.peek());
-	}: create_review(RawOrigin::Signed(caller), s,prj_id)
-	// verify {
-	// 	assert_eq!(Reviews::<T>::get(1), Some(rev));
-	// }
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+	}: {
+		Chocolate::<T>::note_transfer(&caller, &recipient);
+	}
+	verify {
+		assert!(<RecentTransfers<T>>::contains_key(&caller, &recipient));
+	}
+
+	set_identity_requirement {
+		let origin = T::ApprovedOrigin::successful_origin();
+	}: _<T::Origin>(origin, true)
+	verify {
+		assert!(<RequireIdentity<T>>::get());
+	}
 }
 
 impl_benchmark_test_suite!(Chocolate, crate::mock::new_test_ext(), crate::mock::Test);
@@ -14,3 +14,19 @@ pub mod project {
 		(3,b"QmdKx4pmnJUP5GdjtpJE2ei4xeaRKQWYwvXGuVY1AbAwDM/review4.json"),
 	];
 }
+
+pub mod query {
+	/// Maximum number of project ids returned by a single paginated query.
+	pub const MAX_PAGE_SIZE: u32 = 100;
+}
+
+pub mod offchain {
+	/// Maximum number of CIDs the off-chain worker fetches per block.
+	pub const MAX_FETCHES_PER_BLOCK: usize = 5;
+	/// Time allowed for each gateway request, in milliseconds.
+	pub const FETCH_TIMEOUT_MS: u64 = 3_000;
+	/// Maximum size of a fetched document, in bytes. Larger documents are malformed.
+	pub const MAX_CONTENT_SIZE: usize = 64 * 1024;
+	/// Number of blocks a signed flag stays valid for.
+	pub const FLAG_LONGEVITY: u32 = 10;
+}
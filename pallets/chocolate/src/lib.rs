@@ -16,27 +16,39 @@ mod tests;
 mod benchmarking;
 
 pub mod constants;
+pub mod migrations;
+pub mod offchain;
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use crate::constants;
-	use chocolate_primitives::{projects::*, users::UserIO};
+	use crate::{
+		constants,
+		offchain::{self, CheckOutcome, ContentSchema, FlagPayload},
+		WeightInfo,
+	};
+	use chocolate_primitives::{cid, projects::*, users::UserIO};
 	use frame_support::{
 		assert_ok,
 		dispatch::DispatchResult,
 		pallet_prelude::*,
 		sp_runtime::{
-			traits::{CheckedDiv, Saturating},
+			traits::{CheckedDiv, Hash, IdentifyAccount, Saturating, Zero},
 			ArithmeticError,
 		},
 	};
-	use frame_system::{pallet_prelude::*, Origin};
-	use orml_traits::{MultiCurrency, MultiReservableCurrency};
+	use frame_system::{
+		offchain::{AppCrypto, CreateSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer},
+		pallet_prelude::*,
+		Origin,
+	};
+	use orml_traits::{BalanceStatus, MultiCurrency, MultiReservableCurrency};
 	use sp_std::{borrow::ToOwned, str, vec::Vec};
 	// Include the ApprovedOrigin type here, and the method to get treasury id, then mint with currencymodule
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		///  Origins that must approve to use the pallet - Should be implemented properly by provider.
@@ -56,6 +68,49 @@ pub mod pallet {
 		type StringLimit: Get<u32> + Member + Parameter + MaybeSerializeDeserialize + Clone;
 		/// Native currency to be used in settling rewards
 		type GetNativeCurrencyId: Get<CurrencyIdOf<Self>>;
+		/// The maximum number of previous metadata entries kept per project.
+		#[pallet::constant]
+		type MaxMetadataHistory: Get<u32>;
+		/// The maximum number of team members on a project, excluding the owner.
+		#[pallet::constant]
+		type MaxTeamMembers: Get<u32>;
+		/// The maximum number of tags on a project.
+		#[pallet::constant]
+		type MaxTags: Get<u32>;
+		/// Native currency reserved from a user for each helpfulness vote, until it is retracted.
+		#[pallet::constant]
+		type VoteDeposit: Get<BalanceOf<Self>>;
+		/// Net helpful votes on a review that earn its author one rank point.
+		#[pallet::constant]
+		type VotesPerRankPoint: Get<u32>;
+		/// The maximum number of times a review can be edited.
+		#[pallet::constant]
+		type MaxReviewEdits: Get<u32>;
+		/// Native currency reserved from a project owner per byte of a review response.
+		#[pallet::constant]
+		type DataDepositPerByte: Get<BalanceOf<Self>>;
+		/// The longest commit or reveal period of a review round, in blocks.
+		#[pallet::constant]
+		type MaxRoundPeriod: Get<Self::BlockNumber>;
+		/// Source of identity judgements on project owners.
+		type IdentityVerifier: IdentityVerifier<Self::AccountId>;
+		/// Source of the proxies and sub-accounts of project owners, who may not review their
+		/// projects.
+		type AccountRelations: AccountRelations<Self::AccountId>;
+		/// How long, in blocks, an account funded by a project owner may not review the owner's
+		/// projects.
+		#[pallet::constant]
+		type TransferWindow: Get<Self::BlockNumber>;
+		/// The identifier type for the keys content verifiers sign flags with.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+		/// Priority of the unsigned flags submitted by the off-chain worker.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// A non-native currency to reserve review collateral in, for benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkCollateralCurrencyId: Get<CurrencyIdOf<Self>>;
 	}
 	// ------------------------------------------------------------Type aliases ---------------------\
 	/// type alias for review - this is the base struct, like the 2nd part of Balancesof
@@ -67,38 +122,189 @@ pub mod pallet {
 	/// Type alias for balance, binding T::Currency to Currency::AccountId and then extracting from that Balance. Accessible via T::BalanceOf.
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
+	/// Type alias for a category or tag
+	pub type LabelOf<T> = BoundedVec<u8, <T as Config>::StringLimit>;
+	/// Type alias for the tags of a project
+	pub type TagsOf<T> = BoundedVec<LabelOf<T>, <T as Config>::MaxTags>;
+	/// Type alias for the project profile
+	pub type ProjectProfileOf<T> = ProjectProfile<<T as Config>::StringLimit>;
 	/// Type alias for reason
 	pub type ReasonOf<T> = Reason<<T as Config>::StringLimit>;
 	/// Type Alias for Bounded Vec
 	pub type BoundedVecOf<U, T> = BoundedVec<U, <T as Config>::StringLimit>;
+	/// Type alias for a review round
+	pub type ReviewRoundOf<T> = ReviewRound<<T as frame_system::Config>::BlockNumber>;
+	/// Type alias for a committed review
+	pub type ReviewCommitOf<T> = ReviewCommit<
+		<T as frame_system::Config>::Hash,
+		CurrencyIdOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+	/// Type alias for a content flag signed by a verifier
+	pub type FlagPayloadOf<T> = FlagPayload<
+		<T as frame_system::offchain::SigningTypes>::Public,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVecOf<u8, T>,
+	>;
 	/// Currency Id for pallet
 	pub type CurrencyIdOf<T> = <<T as Config>::Currency as MultiCurrency<
 		<T as frame_system::Config>::AccountId,
 	>>::CurrencyId;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Storage map from the project index - id to the projects. getters are for json rpc.
 	#[pallet::storage]
 	#[pallet::getter(fn get_projects)]
 	pub type Projects<T: Config> = StorageMap<_, Blake2_128Concat, ProjectID, ProjectAl<T>>;
-	/// Storage double map from the userid and projectid to the reviews.
-	/// I.e A user owns many reviews,each belonging to a unique project.
+	/// Storage map from the review index - id to the reviews.
 	#[pallet::storage]
-	pub type Reviews<T: Config> = StorageDoubleMap<
+	#[pallet::getter(fn get_reviews)]
+	pub type Reviews<T: Config> = StorageMap<_, Blake2_128Concat, ReviewID, ReviewAl<T>>;
+	/// Storage double map from the userid and projectid to the user's latest review of the project.
+	/// A user can review a project again once its metadata version changes.
+	#[pallet::storage]
+	#[pallet::getter(fn latest_review)]
+	pub type LatestReviews<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
 		Blake2_128Concat,
 		ProjectID,
-		ReviewAl<T>,
+		ReviewID,
 	>;
+	/// Storage value for review index. Increment as we go.
+	/// Analogous to 1+length of review map. it starts at 1.
+	#[pallet::storage]
+	pub type NextReviewIndex<T: Config> = StorageValue<_, ReviewID>;
 	/// Storage value for project index. Increment as we go.
 	/// Analogous to 1+length of project map. it starts at 1.
 	#[pallet::storage]
 	pub type NextProjectIndex<T: Config> = StorageValue<_, ProjectID>;
+	/// Storage map from the project id to the account proposed as its next owner.
+	/// Cleared once the proposed owner accepts or the project is closed.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_owner)]
+	pub type PendingOwners<T: Config> = StorageMap<_, Blake2_128Concat, ProjectID, T::AccountId>;
+	/// Storage map from the project id to its team members and their roles.
+	#[pallet::storage]
+	#[pallet::getter(fn project_team)]
+	pub type ProjectTeams<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ProjectID,
+		BoundedVec<(T::AccountId, ProjectRole), T::MaxTeamMembers>,
+		ValueQuery,
+	>;
+	/// Storage double map from a sender and recipient to the block of the sender's latest
+	/// transfer to the recipient. Fed by `note_transfer` for every sender, as any of them may own
+	/// a project later. Records older than `TransferWindow` are ignored and pruned when looked up.
+	#[pallet::storage]
+	#[pallet::getter(fn recent_transfer)]
+	pub type RecentTransfers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+	>;
+	/// Registry of project categories, managed by governance.
+	#[pallet::storage]
+	pub type Categories<T: Config> = StorageMap<_, Blake2_128Concat, LabelOf<T>, ()>;
+	/// Storage map from the project id to its tags.
+	#[pallet::storage]
+	#[pallet::getter(fn project_tags)]
+	pub type ProjectTags<T: Config> = StorageMap<_, Blake2_128Concat, ProjectID, TagsOf<T>, ValueQuery>;
+	/// Index from a category to the ids of the projects in it.
+	#[pallet::storage]
+	pub type ProjectsByCategory<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		LabelOf<T>,
+		Twox64Concat,
+		ProjectID,
+		(),
+	>;
+	/// Index from a tag to the ids of the open projects carrying it.
+	#[pallet::storage]
+	pub type ProjectsByTag<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, LabelOf<T>, Twox64Concat, ProjectID, ()>;
+	/// Index from a status to the ids of the projects in it.
+	#[pallet::storage]
+	pub type ProjectsByStatus<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, Status, Twox64Concat, ProjectID, ()>;
+	/// Index of the reviews awaiting judgement.
+	#[pallet::storage]
+	pub type ProposedReviews<T: Config> = StorageMap<_, Twox64Concat, ReviewID, ()>;
+	/// Storage map from the project id to its previous metadata, oldest first.
+	/// Holds at most `MaxMetadataHistory` entries, the oldest is dropped when full.
+	#[pallet::storage]
+	#[pallet::getter(fn metadata_history)]
+	pub type MetadataHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ProjectID,
+		BoundedVec<BoundedVecOf<u8, T>, T::MaxMetadataHistory>,
+		ValueQuery,
+	>;
+	/// The project's response to a review, as an IPFS CID, with the account that posted it
+	/// and the deposit held from them. Keyed by project id then review id.
+	#[pallet::storage]
+	#[pallet::getter(fn review_response)]
+	pub type ReviewResponses<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProjectID,
+		Blake2_128Concat,
+		ReviewID,
+		(BoundedVecOf<u8, T>, T::AccountId, BalanceOf<T>),
+	>;
+	/// Helpfulness votes on accepted reviews, keyed by review id then voter.
+	/// `true` when the voter found the review helpful.
+	#[pallet::storage]
+	pub type ReviewVotes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ReviewID,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+	>;
+	/// Storage map from the project id to its latest commit-reveal review round.
+	/// The project is in commit-reveal mode while the round is open.
+	#[pallet::storage]
+	#[pallet::getter(fn review_round)]
+	pub type ReviewRounds<T: Config> = StorageMap<_, Blake2_128Concat, ProjectID, ReviewRoundOf<T>>;
+	/// Storage double map from the reviewer and project id to their unrevealed review.
+	#[pallet::storage]
+	pub type ReviewCommits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ProjectID,
+		ReviewCommitOf<T>,
+	>;
+	/// Accounts whose `choc` keys may flag content from the off-chain worker.
+	#[pallet::storage]
+	pub type ContentVerifiers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+	/// Content flagged as unavailable or malformed, until a moderator clears it.
+	#[pallet::storage]
+	#[pallet::getter(fn content_flag)]
+	pub type Flags<T: Config> =
+		StorageMap<_, Blake2_128Concat, ContentRef, FlagReason>;
+	/// Whether a project owner needs a `Verified` identity for the project to be accepted.
+	/// Off unless governance turns it on.
+	#[pallet::storage]
+	#[pallet::getter(fn require_identity)]
+	pub type RequireIdentity<T: Config> = StorageValue<_, bool, ValueQuery>;
 	// Pallets use events to inform users when important changes are made.
 	// https://substrate.dev/docs/en/knowledgebase/runtime/events
 	#[pallet::event]
@@ -107,12 +313,60 @@ pub mod pallet {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [owner, cid, project_id]
 		ProjectCreated(T::AccountId, BoundedVec<u8, T::StringLimit>, ProjectID),
-		/// parameters. [owner, project_id]
-		ReviewCreated(T::AccountId, ProjectID),
-		/// parameters [owner, project_id]
-		ReviewAccepted(T::AccountId, ProjectID),
+		/// parameters. [owner, project_id, review_id]
+		ReviewCreated(T::AccountId, ProjectID, ReviewID),
+		/// Parameters [reviewer, project_id, review_id]
+		ReviewAccepted(T::AccountId, ProjectID, ReviewID),
+		/// Parameters [reviewer, project_id, review_id]
+		ReviewRejected(T::AccountId, ProjectID, ReviewID),
 		/// Parameters [project_id]
 		ProjectAccepted(ProjectID),
+		/// Parameters [reviewer, project_id, edit_count]
+		ReviewEdited(T::AccountId, ProjectID, u32),
+		/// Parameters [voter, review_id, helpful]
+		ReviewVoted(T::AccountId, ReviewID, bool),
+		/// Parameters [voter, review_id]
+		ReviewVoteRetracted(T::AccountId, ReviewID),
+		/// Parameters [review_id, response]
+		ReviewResponded(ReviewID, BoundedVecOf<u8, T>),
+		/// Parameters [review_id]
+		ReviewResponseRemoved(ReviewID),
+		/// Parameters [project_id, metadata_version]
+		ProjectMetadataUpdated(ProjectID, u32),
+		/// Parameters [project_id]
+		ProjectClosed(ProjectID),
+		/// Parameters [project_id, proposed_owner]
+		OwnershipProposed(ProjectID, T::AccountId),
+		/// Parameters [project_id, old_owner, new_owner]
+		OwnershipTransferred(ProjectID, T::AccountId, T::AccountId),
+		/// Parameters [project_id, member, role]
+		TeamMemberSet(ProjectID, T::AccountId, ProjectRole),
+		/// Parameters [project_id, member]
+		TeamMemberRemoved(ProjectID, T::AccountId),
+		/// Parameters [project_id, funder, amount]
+		RewardToppedUp(ProjectID, T::AccountId, BalanceOf<T>),
+		/// Parameters [category]
+		CategoryAdded(LabelOf<T>),
+		/// Parameters [category]
+		CategoryRemoved(LabelOf<T>),
+		/// Parameters [project_id]
+		ProjectTagsSet(ProjectID),
+		/// Parameters [project_id, commit_end, reveal_end]
+		ReviewRoundStarted(ProjectID, T::BlockNumber, T::BlockNumber),
+		/// Parameters [reviewer, project_id]
+		ReviewCommitted(T::AccountId, ProjectID),
+		/// Parameters [reviewer, project_id]
+		CommitForfeited(T::AccountId, ProjectID),
+		/// Parameters [verifier]
+		ContentVerifierAdded(T::AccountId),
+		/// Parameters [verifier]
+		ContentVerifierRemoved(T::AccountId),
+		/// Parameters [content, reason, verifier]
+		ContentFlagged(ContentRef, FlagReason, T::AccountId),
+		/// Parameters [content]
+		FlagCleared(ContentRef),
+		/// Parameters [required]
+		IdentityRequirementSet(bool),
 	}
 	// Errors inform users that something went wrong.
 	#[pallet::error]
@@ -120,12 +374,18 @@ pub mod pallet {
 		NoneValue,
 		/// The project does not exist
 		NoProjectWithId,
-		/// The reviewer has already placed a review on this project with following id
+		/// The reviewer has a pending review of this project, or has reviewed its current metadata
 		DuplicateReview,
 		/// The index exceeds max usize.
 		StorageOverflow,
 		/// Project owners cannot review their projects
 		OwnerReviewedProject,
+		/// Project team members cannot review their projects
+		TeamMemberReviewedProject,
+		/// Proxies and sub-accounts of a project owner cannot review the owner's projects
+		OwnerProxyReviewedProject,
+		/// The reviewer received funds from the project owner too recently
+		FundedByOwner,
 		/// Insufficient funds for performing a task. Add more funds to your account/call/reserve.
 		InsufficientBalance,
 		/// The reward on the project isn't same as reserve
@@ -138,12 +398,143 @@ pub mod pallet {
 		ReviewNotFound,
 		/// The call to accept must be on a proposed review with appropriate state
 		AcceptingNotProposed,
+		/// The call to reject must be on a proposed review
+		RejectingNotProposed,
+		/// A review can't be rejected for passing requirements
+		InvalidRejectionReason,
+		/// `RequireIdentity` is set and the project owner's identity is not verified
+		UnverifiedOwner,
 		/// The checked division method failed, either due to overflow/underflow or because of division by zero.
 		CheckedDivisionFailed,
 		/// Review score is out of range 1-5
 		ReviewScoreOutOfRange,
 		/// Native token cannot be used as collateral.
 		NativeCollateral,
+		/// Only the project owner can perform this action.
+		NotProjectOwner,
+		/// The project name is empty, too long or not printable utf-8.
+		InvalidProjectName,
+		/// The project website is not an http(s) url.
+		InvalidWebsite,
+		/// The project category is not lowercase ascii letters, digits and dashes.
+		InvalidCategory,
+		/// The token symbol is not uppercase ascii letters and digits.
+		InvalidTokenSymbol,
+		/// The project has been closed.
+		ProjectClosed,
+		/// The project still has reviews awaiting judgement.
+		PendingReviews,
+		/// The caller has not been proposed as the owner of this project.
+		NotPendingOwner,
+		/// The project is already owned by this account.
+		AlreadyProjectOwner,
+		/// The account has a review of the project awaiting judgement, or an unrevealed commit.
+		HasPendingReview,
+		/// The caller's role in the project team does not allow this action.
+		InsufficientTeamRole,
+		/// The project team is full.
+		TooManyTeamMembers,
+		/// The account is not a member of the project team.
+		NotTeamMember,
+		/// The reward would exceed the reward cap.
+		RewardCapExceeded,
+		/// The category is not in the registry.
+		CategoryNotFound,
+		/// The category is already in the registry.
+		CategoryExists,
+		/// The category still has projects in it.
+		CategoryInUse,
+		/// A tag is empty, too long or not lowercase ascii letters, digits and dashes.
+		InvalidTag,
+		/// The project metadata or review content is not a valid IPFS CID.
+		InvalidCid,
+		/// Only registered users can vote on reviews.
+		NotRegisteredUser,
+		/// The review has no response.
+		NoResponse,
+		/// The project has more review responses than the count given to close it.
+		TooManyResponses,
+		/// Only accepted reviews can be voted on.
+		ReviewNotAccepted,
+		/// Reviewers cannot vote on their own reviews.
+		SelfVote,
+		/// The user has already voted on this review.
+		AlreadyVoted,
+		/// The user has not voted on this review.
+		NoVote,
+		/// Only proposed reviews that were not revealed from a review round can be edited.
+		ReviewNotEditable,
+		/// The review has been edited `MaxReviewEdits` times.
+		TooManyEdits,
+		/// The project has an open commit-reveal review round.
+		ReviewRoundActive,
+		/// Review round periods must be between 1 and `MaxRoundPeriod` blocks.
+		InvalidReviewRound,
+		/// Reviews can only be committed during the commit phase of an open round.
+		NotCommitPhase,
+		/// Reviews can only be revealed during the reveal phase of their round.
+		NotRevealPhase,
+		/// The reviewer already has an unrevealed review on this project.
+		AlreadyCommitted,
+		/// The reviewer has no unrevealed review on this project.
+		NoCommit,
+		/// The revealed review does not match the commitment.
+		CommitmentMismatch,
+		/// Commits can only be forfeited once their reveal phase is over.
+		RevealPhaseNotOver,
+		/// The account is not a content verifier.
+		NotContentVerifier,
+		/// The account is already a content verifier.
+		ContentVerifierExists,
+		/// The flag is too old, or the content no longer points to the flagged CID.
+		StaleFlag,
+		/// The content has already been flagged.
+		AlreadyFlagged,
+		/// The content has not been flagged.
+		NotFlagged,
+	}
+	impl<T> From<ProfileError> for Error<T> {
+		fn from(error: ProfileError) -> Self {
+			match error {
+				ProfileError::InvalidName => Error::<T>::InvalidProjectName,
+				ProfileError::InvalidWebsite => Error::<T>::InvalidWebsite,
+				ProfileError::InvalidCategory => Error::<T>::InvalidCategory,
+				ProfileError::InvalidTokenSymbol => Error::<T>::InvalidTokenSymbol,
+			}
+		}
+	}
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Check the content behind pending CIDs. Idle until an IPFS gateway is configured.
+		fn offchain_worker(block_number: T::BlockNumber) {
+			if let Err(error) = Pallet::<T>::verify_pending_content(block_number) {
+				log::warn!(target: "runtime::chocolate", "Content verification failed: {}", error);
+			}
+		}
+	}
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+		/// Only flags signed by a registered content verifier, for the content's current CID.
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (payload, signature) = match call {
+				Call::flag_content { payload, signature } => (payload, signature),
+				_ => return InvalidTransaction::Call.into(),
+			};
+			if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into()
+			}
+			match Pallet::<T>::check_flag(payload) {
+				Ok(_) => ValidTransaction::with_tag_prefix("ChocolateFlag")
+					.priority(T::UnsignedPriority::get())
+					.and_provides(&payload.content)
+					.longevity(constants::offchain::FLAG_LONGEVITY.into())
+					.propagate(true)
+					.build(),
+				Err(Error::<T>::NotContentVerifier) => InvalidTransaction::BadSigner.into(),
+				Err(_) => InvalidTransaction::Stale.into(),
+			}
+		}
 	}
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
@@ -151,141 +542,900 @@ pub mod pallet {
 		/// Create a project
 		///  
 		/// - Init: Index starts at 1
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,3))]
+		/// - The profile must pass `ProjectProfile::validate`
+		/// - The profile metadata must be an IPFS CID, optionally with a path
+		#[pallet::weight(T::WeightInfo::create_project())]
 		pub fn create_project(
 			origin: OriginFor<T>,
-			project_meta: BoundedVec<u8, T::StringLimit>,
+			profile: ProjectProfileOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// CHECKS
+			profile.validate().map_err(Error::<T>::from)?;
+			ensure!(cid::validate(&profile.metadata).is_ok(), Error::<T>::InvalidCid);
+			let category = profile.category.clone();
+			ensure!(<Categories<T>>::contains_key(&category), Error::<T>::CategoryNotFound);
+			let project_meta = profile.metadata.clone();
+			let index = <NextProjectIndex<T>>::get().unwrap_or(1);
+			let new_index = index.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+			let mut user = T::UsersOutlet::get_or_create_default(&who)?;
+			let not_own_project = user.project_id.is_none();
+			ensure!(not_own_project, Error::<T>::AlreadyOwnsProject);
+			ensure!(Pallet::<T>::can_reward(&who), Error::<T>::InsufficientBalance);
+			// Init structs.
+			let mut project = ProjectAl::<T>::new(who.clone(), profile);
+			// FALLIBLE MUTATIONS
+			Pallet::<T>::reserve_reward(&mut project)?;
+			user.project_id = Some(index);
+			// STORAGE MUTATIONS
+			<ProjectsByStatus<T>>::insert(project.proposal_status.status, index, ());
+			<ProjectsByCategory<T>>::insert(category, index, ());
+			<Projects<T>>::insert(index, project);
+			<NextProjectIndex<T>>::put(new_index);
+			T::UsersOutlet::update_user(&who, user).expect("User should already exist");
+			Self::deposit_event(Event::ProjectCreated(who, project_meta, index));
+			Ok(())
+		}
+		/// Create a review, reserve required collateral and increase total of user trust scores on project.
+		///
+		/// The overall score and each of the `dimension_scores`, ordered as `RatingDimension::ALL`,
+		/// must be within `MIN_SCORE..=MAX_SCORE`.
+		#[pallet::weight(T::WeightInfo::create_review())]
+		pub fn create_review(
+			origin: OriginFor<T>,
+			review_meta: (u8, BoundedVecOf<u8, T>),
+			dimension_scores: DimensionScores,
+			project_id: ProjectID,
+			collateral_currency_id: CurrencyIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let native_id =  T::GetNativeCurrencyId::get();
+			// CHECKS & Inits
+			let this_project =
+				<Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			Pallet::<T>::ensure_can_review(project_id, &this_project, &who)?;
+			ensure!(!Pallet::<T>::in_review_round(project_id), Error::<T>::ReviewRoundActive);
+			Pallet::<T>::ensure_valid_review(&review_meta, &dimension_scores)?;
+			ensure!( collateral_currency_id != native_id, Error::<T>::NativeCollateral);
+			let reserve = Pallet::<T>::can_collateralise(collateral_currency_id, &who)?;
+			// Fallible MUTATIONS
+			Pallet::<T>::collateralise(collateral_currency_id, &who, reserve)?;
+			// STORAGE MUTATIONS
+			Pallet::<T>::insert_review(
+				who,
+				project_id,
+				this_project,
+				review_meta,
+				dimension_scores,
+				collateral_currency_id,
+				false,
+			)
+		}
+		/// Edit the content and scores of a review that has not been judged yet.
+		/// Reviews can be edited up to `MaxReviewEdits` times, and not during a review round.
+		/// Reviews revealed from a round can't be edited, so reveals stay binding.
+		/// Changing the content clears any flag on it, for the off-chain worker to check again.
+		#[pallet::weight(T::WeightInfo::edit_review())]
+		pub fn edit_review(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+			review_meta: (u8, BoundedVecOf<u8, T>),
+			dimension_scores: DimensionScores,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let (review_id, mut review) = Pallet::<T>::latest_review_of(&who, project_id)?;
+			// CHECKS
+			let is_proposed = review.proposal_status.status.eq(&Status::Proposed);
+			ensure!(is_proposed && !review.revealed, Error::<T>::ReviewNotEditable);
+			ensure!(review.edit_count < T::MaxReviewEdits::get(), Error::<T>::TooManyEdits);
+			ensure!(!Pallet::<T>::in_review_round(project_id), Error::<T>::ReviewRoundActive);
+			Pallet::<T>::ensure_valid_review(&review_meta, &dimension_scores)?;
+			let content_changed = review.content.ne(&review_meta.1);
+			// MUTATIONS
+			review.review_score = review_meta.0;
+			review.content = review_meta.1;
+			review.dimension_scores = dimension_scores;
+			review.edit_count = review.edit_count.saturating_add(1);
+			let edit_count = review.edit_count;
+			// STORAGE MUTATIONS
+			if content_changed {
+				<Flags<T>>::remove(ContentRef::Review(review_id));
+			}
+			<Reviews<T>>::insert(review_id, review);
+			Self::deposit_event(Event::ReviewEdited(who, project_id, edit_count));
+			Ok(())
+		}
+		/// Vote on whether an accepted review was helpful, reserving `VoteDeposit`.
+		/// Every `VotesPerRankPoint` net helpful votes earn the author a rank point,
+		/// which also adds to the review's weight in the project's rating.
+		///
+		/// **Call requirements**:
+		/// - Origin must be a registered user other than the reviewer
+		#[pallet::weight(T::WeightInfo::vote_review())]
+		pub fn vote_review(
+			origin: OriginFor<T>,
+			review_id: ReviewID,
+			helpful: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let mut review = <Reviews<T>>::get(review_id).ok_or(Error::<T>::ReviewNotFound)?;
+			// CHECKS
+			ensure!(T::UsersOutlet::check_user_exists(&who), Error::<T>::NotRegisteredUser);
+			ensure!(who.ne(&review.user_id), Error::<T>::SelfVote);
+			let is_accepted = review.proposal_status.status.eq(&Status::Accepted);
+			ensure!(is_accepted, Error::<T>::ReviewNotAccepted);
+			let voted = <ReviewVotes<T>>::contains_key(review_id, &who);
+			ensure!(!voted, Error::<T>::AlreadyVoted);
+			// FALLIBLE MUTATIONS
+			T::Currency::reserve(T::GetNativeCurrencyId::get(), &who, T::VoteDeposit::get())?;
+			// MUTATIONS
+			if helpful {
+				review.helpful_votes = review.helpful_votes.saturating_add(1);
+			} else {
+				review.unhelpful_votes = review.unhelpful_votes.saturating_add(1);
+			}
+			// STORAGE MUTATIONS
+			Pallet::<T>::update_helpfulness(review_id, &mut review)?;
+			<Reviews<T>>::insert(review_id, review);
+			<ReviewVotes<T>>::insert(review_id, &who, helpful);
+			Self::deposit_event(Event::ReviewVoted(who, review_id, helpful));
+			Ok(())
+		}
+
+		/// Respond to a review of a project with an IPFS CID, replacing any earlier response.
+		/// `DataDepositPerByte` of the response is reserved from the caller until it is removed
+		/// or the project is closed.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner, or a team member who can respond
+		#[pallet::weight(T::WeightInfo::respond_to_review())]
+		pub fn respond_to_review(
+			origin: OriginFor<T>,
+			review_id: ReviewID,
+			response: BoundedVecOf<u8, T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let review = <Reviews<T>>::get(review_id).ok_or(Error::<T>::ReviewNotFound)?;
+			let project =
+				<Projects<T>>::get(review.project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let native_id = T::GetNativeCurrencyId::get();
+			let length = BalanceOf::<T>::from(response.len() as u32);
+			let deposit = T::DataDepositPerByte::get().saturating_mul(length);
+			let previous = <ReviewResponses<T>>::get(review.project_id, review_id);
+			// CHECKS
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			Pallet::<T>::ensure_permitted(&project, review.project_id, &who, ProjectRole::can_respond)?;
+			ensure!(cid::validate(&response).is_ok(), Error::<T>::InvalidCid);
+			// FALLIBLE MUTATIONS
+			T::Currency::reserve(native_id, &who, deposit)?;
+			if let Some((_, depositor, previous_deposit)) = previous {
+				T::Currency::unreserve(native_id, &depositor, previous_deposit);
+			}
+			// STORAGE MUTATIONS
+			let entry = (response.clone(), who, deposit);
+			<ReviewResponses<T>>::insert(review.project_id, review_id, entry);
+			Self::deposit_event(Event::ReviewResponded(review_id, response));
+			Ok(())
+		}
+
+		/// Remove the response to a review, releasing its deposit to whoever posted it.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner, or a team member who can respond
+		#[pallet::weight(T::WeightInfo::remove_review_response())]
+		pub fn remove_review_response(origin: OriginFor<T>, review_id: ReviewID) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let review = <Reviews<T>>::get(review_id).ok_or(Error::<T>::ReviewNotFound)?;
+			let project =
+				<Projects<T>>::get(review.project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let (_, depositor, deposit) = <ReviewResponses<T>>::get(review.project_id, review_id)
+				.ok_or(Error::<T>::NoResponse)?;
+			// CHECKS
+			Pallet::<T>::ensure_permitted(&project, review.project_id, &who, ProjectRole::can_respond)?;
+			// MUTATIONS
+			T::Currency::unreserve(T::GetNativeCurrencyId::get(), &depositor, deposit);
+			// STORAGE MUTATIONS
+			<ReviewResponses<T>>::remove(review.project_id, review_id);
+			Self::deposit_event(Event::ReviewResponseRemoved(review_id));
+			Ok(())
+		}
+
+		/// Retract a helpfulness vote, releasing its deposit.
+		#[pallet::weight(T::WeightInfo::retract_vote())]
+		pub fn retract_vote(origin: OriginFor<T>, review_id: ReviewID) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let helpful = <ReviewVotes<T>>::get(review_id, &who).ok_or(Error::<T>::NoVote)?;
+			let mut review = <Reviews<T>>::get(review_id).ok_or(Error::<T>::ReviewNotFound)?;
+			// MUTATIONS
+			if helpful {
+				review.helpful_votes = review.helpful_votes.saturating_sub(1);
+			} else {
+				review.unhelpful_votes = review.unhelpful_votes.saturating_sub(1);
+			}
+			T::Currency::unreserve(T::GetNativeCurrencyId::get(), &who, T::VoteDeposit::get());
+			// STORAGE MUTATIONS
+			Pallet::<T>::update_helpfulness(review_id, &mut review)?;
+			<Reviews<T>>::insert(review_id, review);
+			<ReviewVotes<T>>::remove(review_id, &who);
+			Self::deposit_event(Event::ReviewVoteRetracted(who, review_id));
+			Ok(())
+		}
+
+		/// Releases collateral and rewards user for a good review.
+		///
+		/// **Call requirements**:
+		/// - Origin must be cacao
+		///
+		#[pallet::weight(T::WeightInfo::accept_review())]
+		pub fn accept_review(
+			origin: OriginFor<T>,
+			user_id: T::AccountId,
+			project_id: ProjectID,
+		) -> DispatchResult {
+			T::ApprovedOrigin::ensure_origin(origin)?;
+			// Values
+			let (review_id, mut review) = Pallet::<T>::latest_review_of(&user_id, project_id)?;
+			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			// CHECKS
+			let is_proposed = review.proposal_status.status.eq(&Status::Proposed);
+			ensure!(is_proposed, Error::<T>::AcceptingNotProposed);
+			ensure!(
+				Pallet::<T>::check_collateral(review.collateral_currency_id, &user_id),
+				Error::<T>::InconsistentCollateral
+			);
+			Pallet::<T>::check_reward(&project)?;
+			let superseded = review.supersedes.and_then(<Reviews<T>>::get);
+			// MUTATIONS - Fallible
+			// The superseded review stops counting towards the project's scores and reviewers.
+			if let Some(old) = &superseded {
+				let weight = old.point_snapshot.saturating_add(old.helpfulness_points);
+				project.remove_scores(old.review_score, &old.dimension_scores, weight);
+				project.total_user_scores =
+					project.total_user_scores.saturating_sub(old.point_snapshot);
+			}
+			Pallet::<T>::reward_user(&user_id, &mut project, &review)?;
+			review.proposal_status.status = Status::Accepted;
+			review.proposal_status.reason = Reason::PassedRequirements;
+			project.pending_reviews = project.pending_reviews.saturating_sub(1);
+			project.add_scores(review.review_score, &review.dimension_scores, review.point_snapshot);
+			// STORAGE MUTATIONS
+			<ProposedReviews<T>>::remove(review_id);
+			<Reviews<T>>::mutate(review_id, |r| {
+				*r = Option::Some(review);
+			});
+			<Projects<T>>::mutate(project_id, |p| {
+				*p = Option::Some(project);
+			});
+			Self::deposit_event(Event::ReviewAccepted(user_id, project_id, review_id));
+			Ok(())
+		}
+		
+		/// Rejects a proposed review. Its collateral is released, unless the review is
+		/// `Malicious`: then the collateral is slashed and the reviewer penalised, which also
+		/// slashes the bond of whoever vouched for them.
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::reject_review())]
+		pub fn reject_review(
+			origin: OriginFor<T>,
+			user_id: T::AccountId,
+			project_id: ProjectID,
+			reason: ReasonOf<T>,
+		) -> DispatchResult {
+			T::ApprovedOrigin::ensure_origin(origin)?;
+			// VALUES
+			let (review_id, mut review) = Pallet::<T>::latest_review_of(&user_id, project_id)?;
+			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let mut user = T::UsersOutlet::get_user_by_id(&user_id).ok_or(Error::<T>::NoneValue)?;
+			let is_malicious = reason.eq(&Reason::Malicious);
+			// CHECKS
+			let is_proposed = review.proposal_status.status.eq(&Status::Proposed);
+			ensure!(is_proposed, Error::<T>::RejectingNotProposed);
+			let passed = reason.eq(&Reason::PassedRequirements);
+			ensure!(!passed, Error::<T>::InvalidRejectionReason);
+			// MUTATIONS
+			review.proposal_status.status = Status::Rejected;
+			review.proposal_status.reason = reason;
+			project.pending_reviews = project.pending_reviews.saturating_sub(1);
+			project.total_user_scores =
+				project.total_user_scores.saturating_sub(review.point_snapshot);
+			user.open_reviews = user.open_reviews.saturating_sub(1);
+			T::UsersOutlet::update_user(&user_id, user)?;
+			if is_malicious {
+				T::Currency::slash_reserved(
+					review.collateral_currency_id,
+					&user_id,
+					T::UserCollateral::get(),
+				);
+				T::UsersOutlet::penalise(&user_id)?;
+			} else {
+				Pallet::<T>::release_collateral(review.collateral_currency_id, &user_id);
+			}
+			// STORAGE MUTATIONS
+			<ProposedReviews<T>>::remove(review_id);
+			<Reviews<T>>::insert(review_id, review);
+			<Projects<T>>::insert(project_id, project);
+			Self::deposit_event(Event::ReviewRejected(user_id, project_id, review_id));
+			Ok(())
+		}
+
+		/// Moves a project to the accepted state. 
+		/// Must be called by Root-like (Council or CES).
+		///
+		/// Under `RequireIdentity`, the owner's identity must be `Verified`.
+		#[pallet::weight(T::WeightInfo::accept_project())]
+		pub fn accept_project(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+		) -> DispatchResult {
+			T::ApprovedOrigin::ensure_origin(origin)?;
+			// VALUES
+			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let is_proposed = project.proposal_status.status.eq(&Status::Proposed);
+			// CHECKS
+			ensure!(is_proposed, Error::<T>::AcceptingNotProposed);
+			Pallet::<T>::ensure_identity_allowed(&project.owner_id)?;
+			Pallet::<T>::check_reward(&project)?;
+			// MUTATIONS
+			Pallet::<T>::set_project_status(project_id, &mut project, Status::Accepted);
+			project.proposal_status.reason = Reason::PassedRequirements;
+
+			<Projects<T>>::mutate(project_id, |p| {
+				*p = Option::Some(project);
+			});
+			Self::deposit_event(Event::ProjectAccepted(project_id));
+			Ok(())
+		}
+
+		/// Update the metadata of a project, keeping the previous metadata in its history.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner, or a team member allowed to update metadata
+		///
+		/// Set `reset_status` to move the project back to proposed, for re-verification.
+		#[pallet::weight(T::WeightInfo::update_project_metadata())]
+		pub fn update_project_metadata(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+			project_meta: BoundedVecOf<u8, T>,
+			reset_status: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			// CHECKS
+			Pallet::<T>::ensure_permitted(&project, project_id, &who, ProjectRole::can_update_metadata)?;
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			ensure!(cid::validate(&project_meta).is_ok(), Error::<T>::InvalidCid);
+			// MUTATIONS
+			let previous = project.set_metadata(project_meta);
+			if reset_status {
+				Pallet::<T>::set_project_status(project_id, &mut project, Status::Proposed);
+				project.proposal_status.reason = Default::default();
+			}
+			let version = project.metadata_version;
+			// STORAGE MUTATIONS
+			Pallet::<T>::archive_metadata(project_id, previous);
+			<Projects<T>>::insert(project_id, project);
+			Self::deposit_event(Event::ProjectMetadataUpdated(project_id, version));
+			Ok(())
+		}
+
+		/// Close a project. Releases what is left of its reward reserve back to the owner,
+		/// and frees the owner to create another project. The project leaves its category,
+		/// and its review responses are removed with their deposits released. Its team, tags,
+		/// review round and metadata history are removed too, as closed projects can't change.
+		///
+		/// `responses` is an upper bound on the number of review responses the project has.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner
+		/// - The project must have no reviews awaiting judgement
+		/// - The project must have at most `responses` review responses
+		#[pallet::weight(T::WeightInfo::close_project(*responses, T::MaxTags::get()))]
+		pub fn close_project(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+			responses: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let project_responses: Vec<_> = <ReviewResponses<T>>::iter_prefix(project_id)
+				.take((responses as usize).saturating_add(1))
+				.collect();
+			// CHECKS
+			ensure!(project.owner_id.eq(&who), Error::<T>::NotProjectOwner);
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			ensure!(project.pending_reviews == 0, Error::<T>::PendingReviews);
+			ensure!(!Pallet::<T>::in_review_round(project_id), Error::<T>::ReviewRoundActive);
+			ensure!(project_responses.len() <= responses as usize, Error::<T>::TooManyResponses);
+			let mut user = T::UsersOutlet::get_user_by_id(&who).ok_or(Error::<T>::NoneValue)?;
+			// MUTATIONS
+			Pallet::<T>::release_reward(&mut project);
+			Pallet::<T>::set_project_status(project_id, &mut project, Status::Closed);
+			user.project_id = None;
+			let native_id = T::GetNativeCurrencyId::get();
+			for (review_id, (_, depositor, deposit)) in project_responses {
+				T::Currency::unreserve(native_id, &depositor, deposit);
+				<ReviewResponses<T>>::remove(project_id, review_id);
+			}
+			// STORAGE MUTATIONS
+			<ProjectsByCategory<T>>::remove(&project.profile().category, project_id);
+			for tag in <ProjectTags<T>>::take(project_id) {
+				<ProjectsByTag<T>>::remove(tag, project_id);
+			}
+			<ProjectTeams<T>>::remove(project_id);
+			<ReviewRounds<T>>::remove(project_id);
+			<MetadataHistory<T>>::remove(project_id);
+			<Projects<T>>::insert(project_id, project);
+			<PendingOwners<T>>::remove(project_id);
+			T::UsersOutlet::update_user(&who, user)?;
+			Self::deposit_event(Event::ProjectClosed(project_id));
+			Ok(())
+		}
+
+		/// Propose a new owner for a project. Ownership only moves once they accept.
+		/// Proposing again replaces the previous proposal.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner
+		#[pallet::weight(T::WeightInfo::propose_owner())]
+		pub fn propose_owner(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			// CHECKS
+			ensure!(project.owner_id.eq(&who), Error::<T>::NotProjectOwner);
+			ensure!(new_owner.ne(&who), Error::<T>::AlreadyProjectOwner);
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			// STORAGE MUTATIONS
+			<PendingOwners<T>>::insert(project_id, new_owner.clone());
+			Self::deposit_event(Event::OwnershipProposed(project_id, new_owner));
+			Ok(())
+		}
+
+		/// Accept ownership of a project. Moves the reward reserve from the old owner to the caller.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the proposed owner
+		/// - Origin must not already own a project
+		/// - Origin must have no review of the project awaiting judgement
+		/// - Under `RequireIdentity`, origin must have a `Verified` identity if the project is accepted
+		#[pallet::weight(T::WeightInfo::accept_ownership())]
+		pub fn accept_ownership(origin: OriginFor<T>, project_id: ProjectID) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let pending = <PendingOwners<T>>::get(project_id);
+			// CHECKS
+			ensure!(pending.as_ref() == Some(&who), Error::<T>::NotPendingOwner);
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			let mut new_user = T::UsersOutlet::get_or_create_default(&who)?;
+			ensure!(new_user.project_id.is_none(), Error::<T>::AlreadyOwnsProject);
+			let has_pending_review = Pallet::<T>::has_pending_review(&who, project_id);
+			ensure!(!has_pending_review, Error::<T>::HasPendingReview);
+			if project.proposal_status.status.eq(&Status::Accepted) {
+				Pallet::<T>::ensure_identity_allowed(&who)?;
+			}
+			let old_owner = project.owner_id.clone();
+			let mut old_user =
+				T::UsersOutlet::get_user_by_id(&old_owner).ok_or(Error::<T>::NoneValue)?;
+			// FALLIBLE MUTATIONS
+			Pallet::<T>::move_reward(&mut project, &who)?;
+			old_user.project_id = None;
+			new_user.project_id = Some(project_id);
+			// STORAGE MUTATIONS
+			T::UsersOutlet::update_user(&old_owner, old_user)?;
+			T::UsersOutlet::update_user(&who, new_user)?;
+			<Projects<T>>::insert(project_id, project);
+			<PendingOwners<T>>::remove(project_id);
+			<ProjectTeams<T>>::mutate(project_id, |team| team.retain(|(member, _)| member.ne(&who)));
+			Self::deposit_event(Event::OwnershipTransferred(project_id, old_owner, who));
+			Ok(())
+		}
+
+		/// Add a member to the project team, or change the role of an existing member.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner, or a team admin
+		/// - The member must have no review of the project awaiting judgement
+		#[pallet::weight(T::WeightInfo::set_team_member())]
+		pub fn set_team_member(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+			member: T::AccountId,
+			role: ProjectRole,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let mut team = <ProjectTeams<T>>::get(project_id);
+			// CHECKS
+			Pallet::<T>::ensure_permitted(&project, project_id, &who, ProjectRole::can_manage_team)?;
+			ensure!(project.owner_id.ne(&member), Error::<T>::AlreadyProjectOwner);
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			let has_pending_review = Pallet::<T>::has_pending_review(&member, project_id);
+			ensure!(!has_pending_review, Error::<T>::HasPendingReview);
+			// MUTATIONS
+			match team.iter_mut().find(|(id, _)| id.eq(&member)) {
+				Some(entry) => entry.1 = role,
+				None => team
+					.try_push((member.clone(), role))
+					.map_err(|_| Error::<T>::TooManyTeamMembers)?,
+			}
+			// STORAGE MUTATIONS
+			<ProjectTeams<T>>::insert(project_id, team);
+			Self::deposit_event(Event::TeamMemberSet(project_id, member, role));
+			Ok(())
+		}
+
+		/// Remove a member from the project team.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner, a team admin or the member leaving
+		#[pallet::weight(T::WeightInfo::remove_team_member())]
+		pub fn remove_team_member(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+			member: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let mut team = <ProjectTeams<T>>::get(project_id);
+			// CHECKS
+			if who.ne(&member) {
+				Pallet::<T>::ensure_permitted(
+					&project,
+					project_id,
+					&who,
+					ProjectRole::can_manage_team,
+				)?;
+			}
+			let position = team.iter().position(|(id, _)| id.eq(&member));
+			let index = position.ok_or(Error::<T>::NotTeamMember)?;
+			// MUTATIONS
+			team.remove(index);
+			// STORAGE MUTATIONS
+			<ProjectTeams<T>>::insert(project_id, team);
+			Self::deposit_event(Event::TeamMemberRemoved(project_id, member));
+			Ok(())
+		}
+
+		/// Add to the reward a project offers its reviewers, up to the reward cap.
+		/// The amount is reserved on the owner's account. Funds from team members are
+		/// transferred to the owner first.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner, or a team member allowed to fund the project
+		#[pallet::weight(T::WeightInfo::top_up_reward())]
+		pub fn top_up_reward(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let native_id = T::GetNativeCurrencyId::get();
+			// VALUES
+			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			// CHECKS
+			Pallet::<T>::ensure_permitted(&project, project_id, &who, ProjectRole::can_fund)?;
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			let new_reward = project.reward.saturating_add(amount);
+			ensure!(new_reward <= T::RewardCap::get(), Error::<T>::RewardCapExceeded);
+			// FALLIBLE MUTATIONS
+			if who.ne(&project.owner_id) {
+				T::Currency::transfer(native_id, &who, &project.owner_id, amount)?;
+			}
+			T::Currency::reserve(native_id, &project.owner_id, amount)?;
+			project.reward = new_reward;
+			// STORAGE MUTATIONS
+			<Projects<T>>::insert(project_id, project);
+			Self::deposit_event(Event::RewardToppedUp(project_id, who, amount));
+			Ok(())
+		}
+
+		/// Add a category to the registry.
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::add_category())]
+		pub fn add_category(origin: OriginFor<T>, category: LabelOf<T>) -> DispatchResult {
+			T::ApprovedOrigin::ensure_origin(origin)?;
+			// CHECKS
+			ensure!(is_valid_label(&category), Error::<T>::InvalidCategory);
+			ensure!(!<Categories<T>>::contains_key(&category), Error::<T>::CategoryExists);
+			// STORAGE MUTATIONS
+			<Categories<T>>::insert(&category, ());
+			Self::deposit_event(Event::CategoryAdded(category));
+			Ok(())
+		}
+
+		/// Remove a category from the registry. The category must have no open projects.
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::remove_category())]
+		pub fn remove_category(origin: OriginFor<T>, category: LabelOf<T>) -> DispatchResult {
+			T::ApprovedOrigin::ensure_origin(origin)?;
+			// CHECKS
+			ensure!(<Categories<T>>::contains_key(&category), Error::<T>::CategoryNotFound);
+			let in_use = <ProjectsByCategory<T>>::iter_key_prefix(&category).next().is_some();
+			ensure!(!in_use, Error::<T>::CategoryInUse);
+			// STORAGE MUTATIONS
+			<Categories<T>>::remove(&category);
+			Self::deposit_event(Event::CategoryRemoved(category));
+			Ok(())
+		}
+
+		/// Replace the tags of a project.
+		/// Weighed as if the project had `MaxTags` tags before, as those are taken out of the
+		/// tag index.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner, or a team member allowed to update metadata
+		#[pallet::weight(T::WeightInfo::set_project_tags(T::MaxTags::get()))]
+		pub fn set_project_tags(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+			tags: TagsOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			// CHECKS
+			Pallet::<T>::ensure_permitted(&project, project_id, &who, ProjectRole::can_update_metadata)?;
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			ensure!(tags.iter().all(|tag| is_valid_label(tag)), Error::<T>::InvalidTag);
+			// STORAGE MUTATIONS
+			for tag in <ProjectTags<T>>::get(project_id) {
+				<ProjectsByTag<T>>::remove(tag, project_id);
+			}
+			for tag in tags.iter() {
+				<ProjectsByTag<T>>::insert(tag, project_id, ());
+			}
+			<ProjectTags<T>>::insert(project_id, tags);
+			Self::deposit_event(Event::ProjectTagsSet(project_id));
+			Ok(())
+		}
+
+		/// Open a commit-reveal review round. Reviews are committed as hashes for `commit_period`
+		/// blocks, then revealed during the following `reveal_period` blocks.
+		/// Open reviews are not accepted while the round is open.
+		///
+		/// **Call requirements**:
+		/// - Origin must be the project owner, or a team member allowed to update metadata
+		#[pallet::weight(T::WeightInfo::start_review_round())]
+		pub fn start_review_round(
+			origin: OriginFor<T>,
+			project_id: ProjectID,
+			commit_period: T::BlockNumber,
+			reveal_period: T::BlockNumber,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			// VALUES
+			let project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let now = <frame_system::Pallet<T>>::block_number();
 			// CHECKS
-			let index = <NextProjectIndex<T>>::get().unwrap_or(1);
-			let new_index = index.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
-			let mut user = T::UsersOutlet::get_or_create_default(&who);
-			let not_own_project = user.project_id.is_none();
-			ensure!(not_own_project, Error::<T>::AlreadyOwnsProject);
-			ensure!(Pallet::<T>::can_reward(&who), Error::<T>::InsufficientBalance);
-			// Init structs.
-			let mut project = ProjectAl::<T>::new(who.clone(), project_meta.clone());
-			// FALLIBLE MUTATIONS
-			Pallet::<T>::reserve_reward(&mut project)?;
-			user.project_id = Some(index);
+			Pallet::<T>::ensure_permitted(&project, project_id, &who, ProjectRole::can_update_metadata)?;
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			ensure!(!Pallet::<T>::in_review_round(project_id), Error::<T>::ReviewRoundActive);
+			let max_period = T::MaxRoundPeriod::get();
+			let periods_valid = [commit_period, reveal_period]
+				.iter()
+				.all(|period| !period.is_zero() && *period <= max_period);
+			ensure!(periods_valid, Error::<T>::InvalidReviewRound);
+			let commit_end = now.saturating_add(commit_period);
+			let reveal_end = commit_end.saturating_add(reveal_period);
 			// STORAGE MUTATIONS
-			<Projects<T>>::insert(index, project);
-			<NextProjectIndex<T>>::put(new_index);
-			T::UsersOutlet::update_user(&who, user).expect("User should already exist");
-			Self::deposit_event(Event::ProjectCreated(who, project_meta, index));
+			<ReviewRounds<T>>::insert(project_id, ReviewRound { commit_end, reveal_end });
+			Self::deposit_event(Event::ReviewRoundStarted(project_id, commit_end, reveal_end));
 			Ok(())
 		}
-		/// Create a review, reserve required collateral and increase total of user trust scores on project.
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,3))]
-		pub fn create_review(
+
+		/// Commit to a review during the commit phase of a round, reserving the collateral.
+		/// The commit counts as an open review of the user until it is revealed or forfeited.
+		///
+		/// The commitment is the hash of `(reviewer, project_id, score, dimension_scores, content,
+		/// salt)`, as passed to `reveal_review`. Binding it to the reviewer and project stops
+		/// others from copying the commitment and replaying the reveal.
+		#[pallet::weight(T::WeightInfo::commit_review())]
+		pub fn commit_review(
 			origin: OriginFor<T>,
-			review_meta: (u8, BoundedVecOf<u8, T>),
 			project_id: ProjectID,
+			commitment: T::Hash,
 			collateral_currency_id: CurrencyIdOf<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let native_id =  T::GetNativeCurrencyId::get();
-			// CHECKS & Inits
-			let mut this_project =
-				<Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
-			ensure!(!<Reviews<T>>::contains_key(&who, project_id), Error::<T>::DuplicateReview);
-			ensure!(this_project.owner_id.ne(&who), Error::<T>::OwnerReviewedProject);
-			ensure!(review_meta.0 <= 5 && review_meta.0 >= 1, Error::<T>::ReviewScoreOutOfRange);
-			ensure!( collateral_currency_id != native_id, Error::<T>::NativeCollateral);
+			// VALUES
+			let project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let round = <ReviewRounds<T>>::get(project_id).ok_or(Error::<T>::NotCommitPhase)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			// CHECKS
+			ensure!(round.is_commit_phase(&now), Error::<T>::NotCommitPhase);
+			Pallet::<T>::ensure_can_review(project_id, &project, &who)?;
+			let committed = <ReviewCommits<T>>::contains_key(&who, project_id);
+			ensure!(!committed, Error::<T>::AlreadyCommitted);
+			let native_id = T::GetNativeCurrencyId::get();
+			ensure!(collateral_currency_id != native_id, Error::<T>::NativeCollateral);
 			let reserve = Pallet::<T>::can_collateralise(collateral_currency_id, &who)?;
+			let mut user = T::UsersOutlet::get_or_create_default(&who)?;
 			// Fallible MUTATIONS
 			Pallet::<T>::collateralise(collateral_currency_id, &who, reserve)?;
-			let user = T::UsersOutlet::get_or_create_default(&who);
-			this_project.total_user_scores =
-				this_project.total_user_scores.saturating_add(user.rank_points);
+			user.open_reviews = user.open_reviews.saturating_add(1);
 			// STORAGE MUTATIONS
-			<Reviews<T>>::insert(
-				who.clone(),
+			T::UsersOutlet::update_user(&who, user)?;
+			<ReviewCommits<T>>::insert(
+				&who,
 				project_id,
-				Review {
-					user_id: who.clone(),
-					content: review_meta.1,
-					project_id,
-					proposal_status: ProposalStatus {
-						status: Default::default(),
-						reason: Default::default(),
-					},
-					point_snapshot: user.rank_points,
-					review_score: review_meta.0,
-					collateral_currency_id,
-				},
+				ReviewCommit { commitment, collateral_currency_id, round },
 			);
-			<Projects<T>>::mutate(project_id, |project| {
-				*project = Some(this_project);
-			});
-			Self::deposit_event(Event::ReviewCreated(who, project_id));
+			Self::deposit_event(Event::ReviewCommitted(who, project_id));
 			Ok(())
 		}
-		/// Releases collateral and rewards user for a good review.
-		///
-		/// **Call requirements**:
-		/// - Origin must be cacao
-		///
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,3))]
-		pub fn accept_review(
+
+		/// Reveal a committed review during the reveal phase of its round.
+		/// The review then counts like one created with `create_review`.
+		#[pallet::weight(T::WeightInfo::reveal_review())]
+		pub fn reveal_review(
 			origin: OriginFor<T>,
-			user_id: T::AccountId,
 			project_id: ProjectID,
+			review_meta: (u8, BoundedVecOf<u8, T>),
+			dimension_scores: DimensionScores,
+			salt: [u8; 32],
 		) -> DispatchResult {
-			T::ApprovedOrigin::ensure_origin(origin)?;
-			// Values
-			let mut review =
-				<Reviews<T>>::get(&user_id, project_id).ok_or(Error::<T>::ReviewNotFound)?;
-			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let commit = <ReviewCommits<T>>::get(&who, project_id).ok_or(Error::<T>::NoCommit)?;
+			let project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			let now = <frame_system::Pallet<T>>::block_number();
 			// CHECKS
-			let is_proposed = review.proposal_status.status.eq(&Status::Proposed);
-			ensure!(is_proposed, Error::<T>::AcceptingNotProposed);
-			ensure!(
-				Pallet::<T>::check_collateral(review.collateral_currency_id, &user_id),
-				Error::<T>::InconsistentCollateral
+			ensure!(commit.round.is_reveal_phase(&now), Error::<T>::NotRevealPhase);
+			let revealed = T::Hashing::hash_of(&(
+				&who,
+				project_id,
+				review_meta.0,
+				dimension_scores,
+				&review_meta.1,
+				salt,
+			));
+			ensure!(revealed == commit.commitment, Error::<T>::CommitmentMismatch);
+			Pallet::<T>::ensure_can_review(project_id, &project, &who)?;
+			Pallet::<T>::ensure_valid_review(&review_meta, &dimension_scores)?;
+			let mut user = T::UsersOutlet::get_user_by_id(&who).ok_or(Error::<T>::NoneValue)?;
+			// MUTATIONS
+			user.open_reviews = user.open_reviews.saturating_sub(1);
+			// STORAGE MUTATIONS
+			T::UsersOutlet::update_user(&who, user)?;
+			<ReviewCommits<T>>::remove(&who, project_id);
+			Pallet::<T>::insert_review(
+				who,
+				project_id,
+				project,
+				review_meta,
+				dimension_scores,
+				commit.collateral_currency_id,
+				true,
+			)
+		}
+
+		/// Slash the collateral of a review that was not revealed before its round ended.
+		/// Callable by anyone.
+		#[pallet::weight(T::WeightInfo::forfeit_commit())]
+		pub fn forfeit_commit(
+			origin: OriginFor<T>,
+			reviewer: T::AccountId,
+			project_id: ProjectID,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			// VALUES
+			let commit =
+				<ReviewCommits<T>>::get(&reviewer, project_id).ok_or(Error::<T>::NoCommit)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			// CHECKS
+			ensure!(!commit.round.is_open(&now), Error::<T>::RevealPhaseNotOver);
+			// MUTATIONS
+			T::Currency::slash_reserved(
+				commit.collateral_currency_id,
+				&reviewer,
+				T::UserCollateral::get(),
 			);
-			Pallet::<T>::check_reward(&project)?;
-			// MUTATIONS - Fallible
-			Pallet::<T>::reward_user(&user_id, &mut project, &review)?;
-			review.proposal_status.status = Status::Accepted;
-			review.proposal_status.reason = Reason::PassedRequirements;
-			project.number_of_reviews = project.number_of_reviews.saturating_add(1);
-			project.total_review_score =
-				project.total_review_score.saturating_add(u64::from(review.review_score));
 			// STORAGE MUTATIONS
-			<Reviews<T>>::mutate(&user_id, project_id, |r| {
-				*r = Option::Some(review);
-			});
-			<Projects<T>>::mutate(project_id, |p| {
-				*p = Option::Some(project);
-			});
-			Self::deposit_event(Event::ReviewCreated(user_id, project_id));
+			if let Some(mut user) = T::UsersOutlet::get_user_by_id(&reviewer) {
+				user.open_reviews = user.open_reviews.saturating_sub(1);
+				T::UsersOutlet::update_user(&reviewer, user)?;
+			}
+			<ReviewCommits<T>>::remove(&reviewer, project_id);
+			Self::deposit_event(Event::CommitForfeited(reviewer, project_id));
 			Ok(())
 		}
-		
-		/// Moves a project to the accepted state. 
+
+		/// Register an account whose `choc` keys may flag content from the off-chain worker.
 		/// Must be called by Root-like (Council or CES).
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn accept_project(
+		#[pallet::weight(T::WeightInfo::add_content_verifier())]
+		pub fn add_content_verifier(origin: OriginFor<T>, verifier: T::AccountId) -> DispatchResult {
+			T::ApprovedOrigin::ensure_origin(origin)?;
+			// CHECKS
+			let exists = <ContentVerifiers<T>>::contains_key(&verifier);
+			ensure!(!exists, Error::<T>::ContentVerifierExists);
+			// STORAGE MUTATIONS
+			<ContentVerifiers<T>>::insert(&verifier, ());
+			Self::deposit_event(Event::ContentVerifierAdded(verifier));
+			Ok(())
+		}
+
+		/// Deregister a content verifier.
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::remove_content_verifier())]
+		pub fn remove_content_verifier(
 			origin: OriginFor<T>,
-			project_id: ProjectID,
+			verifier: T::AccountId,
 		) -> DispatchResult {
 			T::ApprovedOrigin::ensure_origin(origin)?;
-			// VALUES
-			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
-			let is_proposed = project.proposal_status.status.eq(&Status::Proposed);
 			// CHECKS
-			ensure!(is_proposed, Error::<T>::AcceptingNotProposed);
-			Pallet::<T>::check_reward(&project)?;
-			// MUTATIONS
-			project.proposal_status.status = Status::Accepted;
-			project.proposal_status.reason = Reason::PassedRequirements;
+			let exists = <ContentVerifiers<T>>::contains_key(&verifier);
+			ensure!(exists, Error::<T>::NotContentVerifier);
+			// STORAGE MUTATIONS
+			<ContentVerifiers<T>>::remove(&verifier);
+			Self::deposit_event(Event::ContentVerifierRemoved(verifier));
+			Ok(())
+		}
 
-			<Projects<T>>::mutate(project_id, |p| {
-				*p = Option::Some(project);
-			});
-			Self::deposit_event(Event::ProjectAccepted(project_id));
+		/// Flag content as unavailable or malformed for moderators.
+		///
+		/// Submitted by the off-chain worker as an unsigned transaction, with a payload signed
+		/// by a content verifier's `choc` key.
+		#[pallet::weight(T::WeightInfo::flag_content())]
+		pub fn flag_content(
+			origin: OriginFor<T>,
+			payload: FlagPayloadOf<T>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			// CHECKS
+			let verifier = Pallet::<T>::check_flag(&payload)?;
+			// STORAGE MUTATIONS
+			<Flags<T>>::insert(&payload.content, payload.reason);
+			Self::deposit_event(Event::ContentFlagged(payload.content, payload.reason, verifier));
+			Ok(())
+		}
+
+		/// Clear a flag once moderators have dealt with the content.
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::clear_flag())]
+		pub fn clear_flag(origin: OriginFor<T>, content: ContentRef) -> DispatchResult {
+			T::ApprovedOrigin::ensure_origin(origin)?;
+			// CHECKS
+			ensure!(<Flags<T>>::contains_key(&content), Error::<T>::NotFlagged);
+			// STORAGE MUTATIONS
+			<Flags<T>>::remove(&content);
+			Self::deposit_event(Event::FlagCleared(content));
+			Ok(())
+		}
+
+		/// Set whether project owners need a `Verified` identity for their projects to be
+		/// accepted or handed over.
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::set_identity_requirement())]
+		pub fn set_identity_requirement(origin: OriginFor<T>, required: bool) -> DispatchResult {
+			T::ApprovedOrigin::ensure_origin(origin)?;
+			// STORAGE MUTATIONS
+			<RequireIdentity<T>>::put(required);
+			Self::deposit_event(Event::IdentityRequirementSet(required));
 			Ok(())
 		}
 	}
@@ -298,24 +1448,18 @@ pub mod pallet {
 		fn can_reward(who: &Self::UserID) -> bool {
 			// Reward in native currency, for now.
 			let currency_id = T::GetNativeCurrencyId::get();
-			let existential = T::Currency::minimum_balance(currency_id);
-			let mut amount = T::RewardCap::get();
-			let reserved = T::Currency::reserved_balance(currency_id, who);
-			// Include existential deposit when removing to avoid dropping reserve acnt. (Most important if having multiple projects. )
-			// To be solved by: ProjectAdmin account only having one project
-			if (reserved % amount) < existential {
-				amount = amount.saturating_add(existential);
-			}
+			let amount = Pallet::<T>::reward_reserve();
 			T::Currency::can_reserve(currency_id, who, amount)
 		}
 
 		fn check_reward(project_struct: &ProjectAl<T>) -> DispatchResult {
-			// If the reward is what is reserved + existential
+			// The project's reward reserve must still be held by the owner.
 			let currency_id = T::GetNativeCurrencyId::get();
-			let reserve = T::Currency::reserved_balance(currency_id, &project_struct.owner_id); // If we had the reward, taking the modulo with reward cap would yield what we have??
+			let reserve = T::Currency::reserved_balance(currency_id, &project_struct.owner_id);
 			let existential = T::Currency::minimum_balance(currency_id);
-			// assume reserve to be a superset of our reward.
-			let is_sufficient = reserve >= (project_struct.reward.saturating_add(existential));
+			// The owner's reserve holds the project's tracked reward reserve, among other deposits.
+			let reward_reserve = project_struct.reward.saturating_add(project_struct.reserve_top_up);
+			let is_sufficient = reserve >= reward_reserve;
 			ensure!(is_sufficient, Error::<T>::RewardInconsistent);
 			// ensure free balance too for the next step
 			let free_balance = T::Currency::free_balance(currency_id, &project_struct.owner_id);
@@ -325,15 +1469,10 @@ pub mod pallet {
 
 		fn reserve_reward(project_struct: &mut ProjectAl<T>) -> DispatchResult {
 			let currency_id = T::GetNativeCurrencyId::get();
-			let existential = T::Currency::minimum_balance(currency_id);
-			let mut amount = T::RewardCap::get();
-			let reserved = T::Currency::reserved_balance(currency_id, &project_struct.owner_id);
-			// modulo test two
-			if (reserved % amount) < existential {
-				amount = amount.saturating_add(existential);
-			}
+			let amount = Pallet::<T>::reward_reserve();
 			T::Currency::reserve(currency_id, &project_struct.owner_id, amount)?;
 			project_struct.reward = T::RewardCap::get();
+			project_struct.reserve_top_up = T::Currency::minimum_balance(currency_id);
 			Ok(())
 		}
 
@@ -357,10 +1496,26 @@ pub mod pallet {
 			project_struct.reward = project_struct.reward.saturating_sub(amount);
 			Ok(())
 		}
+
+		fn release_reward(project_struct: &mut ProjectAl<T>) -> Self::Balance {
+			let currency_id = T::GetNativeCurrencyId::get();
+			let amount = project_struct.reward.saturating_add(project_struct.reserve_top_up);
+			let missing = T::Currency::unreserve(currency_id, &project_struct.owner_id, amount);
+			project_struct.reward = Zero::zero();
+			project_struct.reserve_top_up = Zero::zero();
+			missing
+		}
 	}
 
 	/// A separate impl pallet<T> for custom functions that aren't extrinsics
 	impl<T: Config> Pallet<T> {
+		/// What a new project reserves: the reward cap and the existential deposit on top.
+		/// Both are tracked on the project, as the owner's reserved balance may also hold
+		/// deposits and bonds from other pallets.
+		pub fn reward_reserve() -> BalanceOf<T> {
+			let existential = T::Currency::minimum_balance(T::GetNativeCurrencyId::get());
+			T::RewardCap::get().saturating_add(existential)
+		}
 		/// checks if the user's collateral is complete and sufficient for the rewarding process.
 		/// Assumed to be used in context where we'll be using this collateral balance immediately.
 		/// E.g for rewarding
@@ -377,7 +1532,8 @@ pub mod pallet {
 		pub fn release_collateral(currency_id: CurrencyIdOf<T>, who: &T::AccountId) {
 			T::Currency::unreserve(currency_id, &who, T::UserCollateral::get());
 		}
-		/// Reward the user for their contribution to the project. Assumed to be called after acceptance.
+		/// Reward the user for their contribution to the project. Assumed to be called after acceptance,
+		/// so the review no longer counts as open for the user.
 		///
 		/// **requires**: check_reward and check_collateral
 		/// # Note
@@ -413,6 +1569,7 @@ pub mod pallet {
 
 			// Mutations - Fallible. Expect: All of these to rollback changes if they fail.
 			user.rank_points = user.rank_points.saturating_add(1);
+			user.open_reviews = user.open_reviews.saturating_sub(1);
 			Pallet::<T>::reward(project, reward_fraction)?;
 			T::Currency::transfer(native_currency, &project.owner_id, who, reward_fraction)?;
 			T::UsersOutlet::update_user(&who, user)?;
@@ -456,24 +1613,451 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Move the project to a new status, keeping the status index in sync.
+		/// Does not write the project itself.
+		pub fn set_project_status(project_id: ProjectID, project: &mut ProjectAl<T>, status: Status) {
+			<ProjectsByStatus<T>>::remove(project.proposal_status.status, project_id);
+			<ProjectsByStatus<T>>::insert(status, project_id, ());
+			project.proposal_status.status = status;
+		}
+
+		/// Ids of the projects in a category, in storage order.
+		/// Returns at most `limit` ids (capped at `MAX_PAGE_SIZE`), starting after `start_after`.
+		pub fn projects_by_category(
+			category: Vec<u8>,
+			start_after: Option<ProjectID>,
+			limit: u32,
+		) -> Vec<ProjectID> {
+			let category: LabelOf<T> = match category.try_into() {
+				Ok(category) => category,
+				Err(_) => return Vec::new(),
+			};
+			let limit = limit.min(constants::query::MAX_PAGE_SIZE) as usize;
+			match start_after {
+				Some(id) => {
+					let start = <ProjectsByCategory<T>>::hashed_key_for(&category, id);
+					<ProjectsByCategory<T>>::iter_key_prefix_from(&category, start)
+						.take(limit)
+						.collect()
+				},
+				None => <ProjectsByCategory<T>>::iter_key_prefix(&category).take(limit).collect(),
+			}
+		}
+
+		/// Ids of the open projects carrying a tag, in storage order.
+		/// Returns at most `limit` ids (capped at `MAX_PAGE_SIZE`), starting after `start_after`.
+		pub fn projects_by_tag(
+			tag: Vec<u8>,
+			start_after: Option<ProjectID>,
+			limit: u32,
+		) -> Vec<ProjectID> {
+			let tag: LabelOf<T> = match tag.try_into() {
+				Ok(tag) => tag,
+				Err(_) => return Vec::new(),
+			};
+			let limit = limit.min(constants::query::MAX_PAGE_SIZE) as usize;
+			match start_after {
+				Some(id) => {
+					let start = <ProjectsByTag<T>>::hashed_key_for(&tag, id);
+					<ProjectsByTag<T>>::iter_key_prefix_from(&tag, start).take(limit).collect()
+				},
+				None => <ProjectsByTag<T>>::iter_key_prefix(&tag).take(limit).collect(),
+			}
+		}
+
+		/// Ids of the projects with a status, in storage order.
+		/// Returns at most `limit` ids (capped at `MAX_PAGE_SIZE`), starting after `start_after`.
+		pub fn projects_by_status(
+			status: Status,
+			start_after: Option<ProjectID>,
+			limit: u32,
+		) -> Vec<ProjectID> {
+			let limit = limit.min(constants::query::MAX_PAGE_SIZE) as usize;
+			match start_after {
+				Some(id) => {
+					let start = <ProjectsByStatus<T>>::hashed_key_for(status, id);
+					<ProjectsByStatus<T>>::iter_key_prefix_from(status, start).take(limit).collect()
+				},
+				None => <ProjectsByStatus<T>>::iter_key_prefix(status).take(limit).collect(),
+			}
+		}
+
+		/// Note a transfer of funds, so the recipient cannot review the sender's projects, current
+		/// or future, for `TransferWindow` blocks.
+		///
+		/// Callers account for `WeightInfo::note_transfer`.
+		pub fn note_transfer(from: &T::AccountId, to: &T::AccountId) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			<RecentTransfers<T>>::insert(from, to, now);
+		}
+
+		/// Whether the sender funded the recipient within `TransferWindow`. Prunes the record
+		/// once it has expired.
+		pub fn funded_recently(from: &T::AccountId, to: &T::AccountId) -> bool {
+			let at = match <RecentTransfers<T>>::get(from, to) {
+				Some(at) => at,
+				None => return false,
+			};
+			let now = <frame_system::Pallet<T>>::block_number();
+			let is_recent = now.saturating_sub(at) < T::TransferWindow::get();
+			if !is_recent {
+				<RecentTransfers<T>>::remove(from, to);
+			}
+			is_recent
+		}
+
+		/// Ensure the account may review the project: the project is open, the account is
+		/// neither its owner, a team member, a proxy or sub-account of the owner, nor funded by
+		/// the owner within `TransferWindow`, and any earlier review by the account has been
+		/// judged and predates the project's current metadata version.
+		pub fn ensure_can_review(
+			project_id: ProjectID,
+			project: &ProjectAl<T>,
+			who: &T::AccountId,
+		) -> DispatchResult {
+			let is_closed = project.proposal_status.status.eq(&Status::Closed);
+			ensure!(!is_closed, Error::<T>::ProjectClosed);
+			if let Ok((_, previous)) = Pallet::<T>::latest_review_of(who, project_id) {
+				let is_proposed = previous.proposal_status.status.eq(&Status::Proposed);
+				let is_outdated = previous.metadata_version < project.metadata_version;
+				ensure!(!is_proposed && is_outdated, Error::<T>::DuplicateReview);
+			}
+			let owner = &project.owner_id;
+			ensure!(owner.ne(who), Error::<T>::OwnerReviewedProject);
+			let is_team_member = Pallet::<T>::team_role(project_id, who).is_some();
+			ensure!(!is_team_member, Error::<T>::TeamMemberReviewedProject);
+			let acts_for_owner = T::AccountRelations::acts_for(who, owner);
+			ensure!(!acts_for_owner, Error::<T>::OwnerProxyReviewedProject);
+			let is_funded = Pallet::<T>::funded_recently(owner, who);
+			ensure!(!is_funded, Error::<T>::FundedByOwner);
+			Ok(())
+		}
+
+		/// Whether the account has a review of the project awaiting judgement, either proposed
+		/// or committed and not yet revealed.
+		pub fn has_pending_review(who: &T::AccountId, project_id: ProjectID) -> bool {
+			let is_proposed = Pallet::<T>::latest_review_of(who, project_id)
+				.map_or(false, |(_, review)| review.proposal_status.status.eq(&Status::Proposed));
+			is_proposed || <ReviewCommits<T>>::contains_key(who, project_id)
+		}
+
+		/// Ensure the scores are in range and the content is a CID.
+		pub fn ensure_valid_review(
+			review_meta: &(u8, BoundedVecOf<u8, T>),
+			dimension_scores: &DimensionScores,
+		) -> DispatchResult {
+			ensure!(is_valid_score(review_meta.0), Error::<T>::ReviewScoreOutOfRange);
+			let scores_valid = dimension_scores.iter().all(|score| is_valid_score(*score));
+			ensure!(scores_valid, Error::<T>::ReviewScoreOutOfRange);
+			ensure!(cid::validate(&review_meta.1).is_ok(), Error::<T>::InvalidCid);
+			Ok(())
+		}
+
+		/// Bring the helpfulness points of an accepted review in line with its votes, moving
+		/// the difference to the author's rank points, if they are still registered, and, while the
+		/// review still counts, its weight in the project rating. Writes the author and project,
+		/// but not the review.
+		pub fn update_helpfulness(review_id: ReviewID, review: &mut ReviewAl<T>) -> DispatchResult {
+			let (reviewer, project_id) = (review.user_id.clone(), review.project_id);
+			let net_votes = review.helpful_votes.saturating_sub(review.unhelpful_votes);
+			let points = net_votes / T::VotesPerRankPoint::get().max(1);
+			if points == review.helpfulness_points {
+				return Ok(())
+			}
+			let previous = review.helpfulness_points;
+			let mut project = <Projects<T>>::get(project_id).ok_or(Error::<T>::NoProjectWithId)?;
+			if !Pallet::<T>::is_superseded(review_id, review) {
+				project.remove_rating_weight(review.review_score, previous);
+				project.add_rating_weight(review.review_score, points);
+			}
+			// The votes still weigh in the rating once the author has deregistered.
+			if let Some(mut user) = T::UsersOutlet::get_user_by_id(&reviewer) {
+				user.rank_points = if points > previous {
+					user.rank_points.saturating_add(points - previous)
+				} else {
+					user.rank_points.saturating_sub(previous - points)
+				};
+				T::UsersOutlet::update_user(&reviewer, user)?;
+			}
+			review.helpfulness_points = points;
+			<Projects<T>>::insert(project_id, project);
+			Ok(())
+		}
+
+		/// Whether a later review by the same author has been accepted in place of this one.
+		pub fn is_superseded(review_id: ReviewID, review: &ReviewAl<T>) -> bool {
+			match Pallet::<T>::latest_review_of(&review.user_id, review.project_id) {
+				Ok((latest_id, _)) if latest_id == review_id => false,
+				// A later review only replaces this one once it is accepted.
+				Ok((_, latest)) if latest.supersedes == Some(review_id) =>
+					latest.proposal_status.status.eq(&Status::Accepted),
+				Ok(_) => true,
+				Err(_) => false,
+			}
+		}
+
+		/// Whether the project has an open commit-reveal review round.
+		pub fn in_review_round(project_id: ProjectID) -> bool {
+			let now = <frame_system::Pallet<T>>::block_number();
+			<ReviewRounds<T>>::get(project_id).map_or(false, |round| round.is_open(&now))
+		}
+
+		/// Store a proposed review whose collateral is reserved, and count it on the project
+		/// and as open for the user. `revealed` marks reviews revealed from a round. Does no checks.
+		///
+		/// Fails if the user has to be created and can't pay the registration deposit.
+		pub fn insert_review(
+			who: T::AccountId,
+			project_id: ProjectID,
+			mut project: ProjectAl<T>,
+			review_meta: (u8, BoundedVecOf<u8, T>),
+			dimension_scores: DimensionScores,
+			collateral_currency_id: CurrencyIdOf<T>,
+			revealed: bool,
+		) -> DispatchResult {
+			let mut user = T::UsersOutlet::get_or_create_default(&who)?;
+			let point_snapshot = user.rank_points;
+			// Replaces the author's accepted review of the project, passing over rejected ones.
+			let supersedes = Pallet::<T>::latest_review_of(&who, project_id).ok().and_then(
+				|(previous_id, previous)| match previous.proposal_status.status {
+					Status::Accepted => Some(previous_id),
+					_ => previous.supersedes,
+				},
+			);
+			user.open_reviews = user.open_reviews.saturating_add(1);
+			T::UsersOutlet::update_user(&who, user)?;
+			let review_id = <NextReviewIndex<T>>::get().unwrap_or(1);
+			project.total_user_scores = project.total_user_scores.saturating_add(point_snapshot);
+			project.pending_reviews = project.pending_reviews.saturating_add(1);
+			<Reviews<T>>::insert(
+				review_id,
+				Review {
+					user_id: who.clone(),
+					content: review_meta.1,
+					project_id,
+					metadata_version: project.metadata_version,
+					proposal_status: ProposalStatus {
+						status: Default::default(),
+						reason: Default::default(),
+					},
+					point_snapshot,
+					review_score: review_meta.0,
+					dimension_scores,
+					edit_count: 0,
+					revealed,
+					helpful_votes: 0,
+					unhelpful_votes: 0,
+					helpfulness_points: 0,
+					supersedes,
+					collateral_currency_id,
+				},
+			);
+			<LatestReviews<T>>::insert(&who, project_id, review_id);
+			<ProposedReviews<T>>::insert(review_id, ());
+			<NextReviewIndex<T>>::put(review_id.saturating_add(1));
+			<Projects<T>>::insert(project_id, project);
+			Self::deposit_event(Event::ReviewCreated(who, project_id, review_id));
+			Ok(())
+		}
+
+		/// The account's latest review of the project, with its id.
+		pub fn latest_review_of(
+			who: &T::AccountId,
+			project_id: ProjectID,
+		) -> Result<(ReviewID, ReviewAl<T>), Error<T>> {
+			let review_id =
+				<LatestReviews<T>>::get(who, project_id).ok_or(Error::<T>::ReviewNotFound)?;
+			let review = <Reviews<T>>::get(review_id).ok_or(Error::<T>::ReviewNotFound)?;
+			Ok((review_id, review))
+		}
+
+		/// The CID the content currently points to.
+		pub fn content_cid(content: &ContentRef) -> Option<BoundedVecOf<u8, T>> {
+			match content {
+				ContentRef::Project(project_id) =>
+					<Projects<T>>::get(project_id).map(|project| project.metadata().clone()),
+				ContentRef::Review(review_id) =>
+					<Reviews<T>>::get(review_id).map(|review| review.content),
+			}
+		}
+
+		/// Check a flag against the chain, returning the verifier that raised it.
+		/// The flag must be recent and for the CID the content currently points to.
+		pub fn check_flag(payload: &FlagPayloadOf<T>) -> Result<T::AccountId, Error<T>> {
+			let verifier = payload.public.clone().into_account();
+			ensure!(<ContentVerifiers<T>>::contains_key(&verifier), Error::<T>::NotContentVerifier);
+			let now = <frame_system::Pallet<T>>::block_number();
+			let expiry =
+				payload.block_number.saturating_add(constants::offchain::FLAG_LONGEVITY.into());
+			ensure!(payload.block_number <= now && now <= expiry, Error::<T>::StaleFlag);
+			let current_cid = Pallet::<T>::content_cid(&payload.content);
+			ensure!(current_cid.as_ref() == Some(&payload.cid), Error::<T>::StaleFlag);
+			ensure!(!<Flags<T>>::contains_key(&payload.content), Error::<T>::AlreadyFlagged);
+			Ok(verifier)
+		}
+
+		/// Fetch the CIDs of proposed projects and reviews, and flag those that are unavailable
+		/// or malformed. Each CID is checked once per node.
+		fn verify_pending_content(block_number: T::BlockNumber) -> Result<(), &'static str> {
+			let gateway = match offchain::gateway() {
+				Some(gateway) => gateway,
+				None => return Ok(()),
+			};
+			let signer = Signer::<T, T::AuthorityId>::any_account();
+			if !signer.can_sign() {
+				return Ok(())
+			}
+			let projects =
+				<ProjectsByStatus<T>>::iter_key_prefix(Status::Proposed).filter_map(|project_id| {
+					let project = <Projects<T>>::get(project_id)?;
+					let content = ContentRef::Project(project_id);
+					Some((content, project.metadata().clone(), ContentSchema::Project))
+				});
+			let reviews = <ProposedReviews<T>>::iter_keys().filter_map(|review_id| {
+				let review = <Reviews<T>>::get(review_id)?;
+				let content = ContentRef::Review(review_id);
+				Some((content, review.content, ContentSchema::Review))
+			});
+			let pending = projects
+				.chain(reviews)
+				.filter(|(content, cid, _)| {
+					!<Flags<T>>::contains_key(content) && !offchain::is_checked(content, cid)
+				})
+				.take(constants::offchain::MAX_FETCHES_PER_BLOCK);
+			for (content, cid, schema) in pending {
+				let reason = match offchain::check_content(&gateway, &cid, schema) {
+					CheckOutcome::Valid => {
+						offchain::mark_checked(&content, &cid);
+						continue
+					},
+					CheckOutcome::Flag(reason) => reason,
+					CheckOutcome::Retry => continue,
+				};
+				let sent = signer.send_unsigned_transaction(
+					|account| FlagPayload {
+						content: content.clone(),
+						cid: cid.clone(),
+						reason,
+						block_number,
+						public: account.public.clone(),
+					},
+					|payload, signature| Call::flag_content { payload, signature },
+				);
+				match sent {
+					Some((_, Ok(()))) => offchain::mark_checked(&content, &cid),
+					_ => return Err("Failed to submit a content flag"),
+				}
+			}
+			Ok(())
+		}
+
+		/// Average ratings of a project, overall and per `RatingDimension`.
+		pub fn project_rating(project_id: ProjectID) -> Option<ProjectRating> {
+			<Projects<T>>::get(project_id).map(|project| project.rating())
+		}
+
+		/// How far the identity of the project's owner has been verified.
+		pub fn owner_identity(project_id: ProjectID) -> Option<IdentityStatus> {
+			<Projects<T>>::get(project_id)
+				.map(|project| T::IdentityVerifier::identity_status(&project.owner_id))
+		}
+
+		/// Ensure the account may own an accepted project under the `RequireIdentity` policy.
+		pub fn ensure_identity_allowed(who: &T::AccountId) -> DispatchResult {
+			if <RequireIdentity<T>>::get() {
+				let verified = T::IdentityVerifier::identity_status(who).eq(&IdentityStatus::Verified);
+				ensure!(verified, Error::<T>::UnverifiedOwner);
+			}
+			Ok(())
+		}
+
+		/// Registered categories, in storage order.
+		/// Returns at most `limit` categories (capped at `MAX_PAGE_SIZE`), starting after `start_after`.
+		pub fn categories(start_after: Option<Vec<u8>>, limit: u32) -> Vec<Vec<u8>> {
+			let limit = limit.min(constants::query::MAX_PAGE_SIZE) as usize;
+			let categories = match start_after.map(LabelOf::<T>::try_from) {
+				Some(Ok(category)) => {
+					let start = <Categories<T>>::hashed_key_for(&category);
+					<Categories<T>>::iter_keys_from(start)
+				},
+				Some(Err(_)) => return Vec::new(),
+				None => <Categories<T>>::iter_keys(),
+			};
+			categories.take(limit).map(|category| category.into_inner()).collect()
+		}
+
+		/// The role of an account in the project's team, if it is a member.
+		pub fn team_role(project_id: ProjectID, who: &T::AccountId) -> Option<ProjectRole> {
+			<ProjectTeams<T>>::get(project_id)
+				.into_iter()
+				.find(|(member, _)| member.eq(who))
+				.map(|(_, role)| role)
+		}
+
+		/// Ensure the account is the project owner, or a team member whose role passes `allowed`.
+		pub fn ensure_permitted(
+			project: &ProjectAl<T>,
+			project_id: ProjectID,
+			who: &T::AccountId,
+			allowed: fn(&ProjectRole) -> bool,
+		) -> DispatchResult {
+			if project.owner_id.eq(who) {
+				return Ok(())
+			}
+			let role = Pallet::<T>::team_role(project_id, who).ok_or(Error::<T>::NotProjectOwner)?;
+			ensure!(allowed(&role), Error::<T>::InsufficientTeamRole);
+			Ok(())
+		}
+
+		/// Move the project's reward reserve, along with its existential top-up, to a new owner
+		/// and make them the owner of the project.
+		///
+		/// Fails if the full reserve could not be moved.
+		pub fn move_reward(project: &mut ProjectAl<T>, new_owner: &T::AccountId) -> DispatchResult {
+			let currency_id = T::GetNativeCurrencyId::get();
+			let amount = project.reward.saturating_add(project.reserve_top_up);
+			let remainder = T::Currency::repatriate_reserved(
+				currency_id,
+				&project.owner_id,
+				new_owner,
+				amount,
+				BalanceStatus::Reserved,
+			)?;
+			ensure!(remainder.is_zero(), Error::<T>::RewardInconsistent);
+			project.owner_id = new_owner.clone();
+			Ok(())
+		}
+
+		/// Append metadata to the project's history, dropping the oldest entry when full.
+		pub fn archive_metadata(project_id: ProjectID, metadata: BoundedVecOf<u8, T>) {
+			<MetadataHistory<T>>::mutate(project_id, |history| {
+				let is_full = history.len() as u32 >= T::MaxMetadataHistory::get();
+				if is_full && !history.is_empty() {
+					history.remove(0);
+				}
+				// Only fails when history is disabled, i.e MaxMetadataHistory is 0.
+				let _ = history.try_push(metadata);
+			});
+		}
+
 		/// Create a project from required data - only for genesis
 		/// Assumes user has already been craeted.
 		/// # Panics
 		/// Panics with expect block if it cannot update the user or reserve the reward amount.
 		pub fn initialize_project(
 			who: T::AccountId,
-			metadata: BoundedVecOf<u8, T>,
+			profile: ProjectProfileOf<T>,
 			status: Status,
 			reason: ReasonOf<T>,
 		) -> ProjectAl<T> {
 			// FALLIBLE MUTATIONS
 			let t = Origin::<T>::Signed(who.clone());
-			assert_ok!(Pallet::<T>::create_project(t.into(), metadata.clone()));
+			assert_ok!(Pallet::<T>::create_project(t.into(), profile));
 			let next_index = <NextProjectIndex<T>>::get().unwrap_or_default();
 			let index = next_index.saturating_sub(1);
 			// STORAGE MUTATIONS
 			let mut project = <Projects<T>>::get(index).unwrap();
-			project.proposal_status.status = status;
+			Pallet::<T>::set_project_status(index, &mut project, status);
 			project.proposal_status.reason = reason;
 			<Projects<T>>::insert(index, project.clone());
 			project
@@ -492,6 +2076,7 @@ pub mod pallet {
 						rev.0,
 						rev.1.to_vec().try_into().expect("Metadata should be within StringLimit"),
 					),
+					[rev.0; NUMBER_OF_DIMENSIONS],
 					project_id,
 					currency_id.to_owned(),
 				);
@@ -515,13 +2100,22 @@ pub mod pallet {
 		/// All accounts used should be endowed with initial balance of the specified currencyId, atleast enough to handle reserve
 		/// Zip may mean users are matched up with projects by index
 		pub init_users: Vec<(T::AccountId, CurrencyIdOf<T>)>,
+		/// Categories to register at genesis
+		pub init_categories: Vec<Vec<u8>>,
+		/// Whether project owners need a `Verified` identity from genesis
+		pub require_identity: bool,
 	}
 	/// By default a generic project or known projects will be shown - polkadot & sisters
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
 			// to-do actually make this known projects. In the meantime, default will do.
-			Self { init_projects: Vec::new(), init_users: Vec::new() }
+			Self {
+				init_projects: Vec::new(),
+				init_users: Vec::new(),
+				init_categories: Vec::new(),
+				require_identity: false,
+			}
 		}
 	}
 
@@ -529,6 +2123,13 @@ pub mod pallet {
 	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		// Genesis build: Creates mock reviews for testing.
 		fn build(&self) {
+			for category in &self.init_categories {
+				let category: LabelOf<T> =
+					category.clone().try_into().expect("Category should be within StringLimit");
+				assert!(is_valid_label(&category), "Genesis categories should be valid labels");
+				<Categories<T>>::insert(category, ());
+			}
+			<RequireIdentity<T>>::put(self.require_identity);
 			// FIXME
 			// Genesis build has been removed. See https://github.com/chocolatenetwork/chocolate-parachain/pull/10. It is now a node script at https://github.com/chocolatenetwork/choc-js
 		}
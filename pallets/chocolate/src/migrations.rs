@@ -0,0 +1,873 @@
+//! Storage migrations of the chocolate pallet.
+//!
+//! Each version migrates the storage of one change to the pallet, and only runs from the
+//! version before it, so the runtime must list them in order. Projects and reviews are
+//! translated within a single block: under try-runtime, `pre_upgrade` checks there are at most
+//! `MAX_MIGRATED_ITEMS` of them.
+
+use crate::{
+	Categories, Config, CurrencyIdOf, LatestReviews, NextReviewIndex, Pallet, Projects,
+	ProjectsByCategory, ProjectsByStatus, ProposedReviews,
+};
+use chocolate_primitives::{
+	projects::{
+		DimensionScores, Project, ProjectID, ProjectProfile, ProposalStatus, Review, Status,
+		NUMBER_OF_DIMENSIONS,
+	},
+	users::UserIO,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	sp_runtime::traits::Saturating,
+	storage::{
+		migration::move_prefix,
+		storage_prefix,
+		types::{StorageDoubleMap, StorageMap},
+	},
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+	Blake2_128Concat, BoundedVec,
+};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use sp_std::marker::PhantomData;
+
+/// The most projects, or reviews, a migration translates. Keeps each migration well within the
+/// weight of a block.
+pub const MAX_MIGRATED_ITEMS: usize = 1_000;
+
+/// Check that a migration from version `from` has few enough items to translate.
+#[cfg(feature = "try-runtime")]
+fn ensure_migratable<T: Config>(from: u16, items: usize) -> Result<(), &'static str> {
+	if Pallet::<T>::on_chain_storage_version() <= from {
+		frame_support::ensure!(items <= MAX_MIGRATED_ITEMS, "Too many items to migrate");
+	}
+	Ok(())
+}
+
+/// Check that the pallet reached version `to`.
+#[cfg(feature = "try-runtime")]
+fn ensure_migrated<T: Config>(to: u16) -> Result<(), &'static str> {
+	frame_support::ensure!(Pallet::<T>::on_chain_storage_version() >= to, "Migration did not run");
+	Ok(())
+}
+
+/// Every migration of the pallet, in order.
+pub type Migrations<T> = (
+	v1::MigrateToV1<T>,
+	v2::MigrateToV2<T>,
+	v3::MigrateToV3<T>,
+	v4::MigrateToV4<T>,
+	v5::MigrateToV5<T>,
+	v6::MigrateToV6<T>,
+	v7::MigrateToV7<T>,
+	v8::MigrateToV8<T>,
+	v9::MigrateToV9<T>,
+);
+
+/// Layouts before the first migration.
+pub mod v0 {
+	use super::*;
+
+	/// `Project` before version 1.
+	#[derive(Encode, Decode)]
+	pub struct ProjectV0<AccountId, Balance, StringLen>
+	where
+		StringLen: Get<u32>,
+	{
+		pub owner_id: AccountId,
+		pub badge: Option<bool>,
+		pub metadata: BoundedVec<u8, StringLen>,
+		pub proposal_status: ProposalStatus<StringLen>,
+		pub reward: Balance,
+		pub total_user_scores: u32,
+		pub total_review_score: u64,
+		pub number_of_reviews: u32,
+	}
+	/// Type alias for a project before version 1
+	pub type ProjectV0Of<T> = ProjectV0<
+		<T as frame_system::Config>::AccountId,
+		crate::BalanceOf<T>,
+		<T as Config>::StringLimit,
+	>;
+
+	/// `Review` before version 5.
+	#[derive(Encode, Decode)]
+	pub struct ReviewV0<AccountId, StringLen, CurrencyId>
+	where
+		StringLen: Get<u32>,
+	{
+		pub proposal_status: ProposalStatus<StringLen>,
+		pub user_id: AccountId,
+		pub content: BoundedVec<u8, StringLen>,
+		pub project_id: ProjectID,
+		pub point_snapshot: u32,
+		pub review_score: u8,
+		pub collateral_currency_id: CurrencyId,
+	}
+	/// Type alias for a review before version 5
+	pub type ReviewV0Of<T> = ReviewV0<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::StringLimit,
+		CurrencyIdOf<T>,
+	>;
+
+	/// `Reviews` before version 5, keyed by author and project.
+	#[frame_support::storage_alias]
+	pub type Reviews<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		ProjectID,
+		ReviewV0Of<T>,
+	>;
+}
+
+/// Version 1 numbers the metadata of projects, starting at zero.
+pub mod v1 {
+	use super::*;
+
+	/// `Project` at version 1.
+	#[derive(Encode, Decode)]
+	pub struct ProjectV1<AccountId, Balance, StringLen>
+	where
+		StringLen: Get<u32>,
+	{
+		pub owner_id: AccountId,
+		pub badge: Option<bool>,
+		pub metadata: BoundedVec<u8, StringLen>,
+		pub metadata_version: u32,
+		pub proposal_status: ProposalStatus<StringLen>,
+		pub reward: Balance,
+		pub total_user_scores: u32,
+		pub total_review_score: u64,
+		pub number_of_reviews: u32,
+	}
+	/// Type alias for a project at version 1
+	pub type ProjectV1Of<T> = ProjectV1<
+		<T as frame_system::Config>::AccountId,
+		crate::BalanceOf<T>,
+		<T as Config>::StringLimit,
+	>;
+
+	/// `Projects` at version 1.
+	#[frame_support::storage_alias]
+	pub type Projects<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ProjectID, ProjectV1Of<T>>;
+
+	/// Migrate `Projects` to version 1.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut projects: Weight = 0;
+			Projects::<T>::translate::<v0::ProjectV0Of<T>, _>(|_, old| {
+				projects = projects.saturating_add(1);
+				Some(ProjectV1 {
+					owner_id: old.owner_id,
+					badge: old.badge,
+					metadata: old.metadata,
+					metadata_version: 0,
+					proposal_status: old.proposal_status,
+					reward: old.reward,
+					total_user_scores: old.total_user_scores,
+					total_review_score: old.total_review_score,
+					number_of_reviews: old.number_of_reviews,
+				})
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(projects.saturating_add(1), projects.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure_migratable::<T>(0, Projects::<T>::iter_keys().count())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure_migrated::<T>(1)
+		}
+	}
+}
+
+/// Version 2 tracks the top-up of the reward reserve and the pending reviews of projects, so
+/// they can be closed.
+pub mod v2 {
+	use super::*;
+
+	/// `Project` at version 2.
+	#[derive(Encode, Decode)]
+	pub struct ProjectV2<AccountId, Balance, StringLen>
+	where
+		StringLen: Get<u32>,
+	{
+		pub owner_id: AccountId,
+		pub badge: Option<bool>,
+		pub metadata: BoundedVec<u8, StringLen>,
+		pub metadata_version: u32,
+		pub proposal_status: ProposalStatus<StringLen>,
+		pub reward: Balance,
+		pub reserve_top_up: Balance,
+		pub total_user_scores: u32,
+		pub total_review_score: u64,
+		pub number_of_reviews: u32,
+		pub pending_reviews: u32,
+	}
+	/// Type alias for a project at version 2
+	pub type ProjectV2Of<T> = ProjectV2<
+		<T as frame_system::Config>::AccountId,
+		crate::BalanceOf<T>,
+		<T as Config>::StringLimit,
+	>;
+
+	/// `Projects` at version 2.
+	#[frame_support::storage_alias]
+	pub type Projects<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ProjectID, ProjectV2Of<T>>;
+
+	/// Migrate `Projects` to version 2.
+	///
+	/// Owners reserved nothing else back then, so anything they have reserved beyond the reward,
+	/// up to the existential deposit, is taken to be the reserve top-up.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+			let native_id = T::GetNativeCurrencyId::get();
+			let existential = T::Currency::minimum_balance(native_id);
+			let mut projects: Weight = 0;
+			Projects::<T>::translate::<v1::ProjectV1Of<T>, _>(|_, old| {
+				projects = projects.saturating_add(1);
+				let reserved = T::Currency::reserved_balance(native_id, &old.owner_id);
+				Some(ProjectV2 {
+					reserve_top_up: reserved.saturating_sub(old.reward).min(existential),
+					owner_id: old.owner_id,
+					badge: old.badge,
+					metadata: old.metadata,
+					metadata_version: old.metadata_version,
+					proposal_status: old.proposal_status,
+					reward: old.reward,
+					total_user_scores: old.total_user_scores,
+					total_review_score: old.total_review_score,
+					number_of_reviews: old.number_of_reviews,
+					pending_reviews: 0,
+				})
+			});
+			let mut reviews: Weight = 0;
+			let mut pending: Weight = 0;
+			for review in v0::Reviews::<T>::iter_values() {
+				reviews = reviews.saturating_add(1);
+				if review.proposal_status.status == Status::Proposed {
+					pending = pending.saturating_add(1);
+					Projects::<T>::mutate(review.project_id, |project| {
+						if let Some(project) = project {
+							project.pending_reviews = project.pending_reviews.saturating_add(1);
+						}
+					});
+				}
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+			// Per project the owner's reserve is read, and each pending review updates its project.
+			let reads = projects.saturating_mul(2).saturating_add(reviews).saturating_add(pending);
+			let writes = projects.saturating_add(pending);
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure_migratable::<T>(1, Projects::<T>::iter_keys().count())?;
+			ensure_migratable::<T>(1, v0::Reviews::<T>::iter_keys().count())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure_migrated::<T>(2)
+		}
+	}
+}
+
+/// Version 3 replaces the metadata of projects with a profile holding it.
+pub mod v3 {
+	use super::*;
+
+	/// Category of the projects created before profiles existed.
+	pub const UNCATEGORISED: &[u8] = b"uncategorised";
+	/// Name of the projects created before profiles existed.
+	pub const UNNAMED: &[u8] = b"Unnamed project";
+	/// Gateway the websites of those projects point to, followed by their metadata.
+	pub const IPFS_GATEWAY: &[u8] = b"https://ipfs.io/ipfs/";
+
+	/// `Project` at version 3.
+	#[derive(Encode, Decode)]
+	pub struct ProjectV3<AccountId, Balance, StringLen>
+	where
+		StringLen: Get<u32>,
+	{
+		pub owner_id: AccountId,
+		pub badge: Option<bool>,
+		pub profile: ProjectProfile<StringLen>,
+		pub metadata_version: u32,
+		pub proposal_status: ProposalStatus<StringLen>,
+		pub reward: Balance,
+		pub reserve_top_up: Balance,
+		pub total_user_scores: u32,
+		pub total_review_score: u64,
+		pub number_of_reviews: u32,
+		pub pending_reviews: u32,
+	}
+	/// Type alias for a project at version 3
+	pub type ProjectV3Of<T> = ProjectV3<
+		<T as frame_system::Config>::AccountId,
+		crate::BalanceOf<T>,
+		<T as Config>::StringLimit,
+	>;
+
+	/// `Projects` at version 3.
+	#[frame_support::storage_alias]
+	pub type Projects<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ProjectID, ProjectV3Of<T>>;
+
+	/// A valid profile for a project created before profiles existed.
+	/// The website points at the project's metadata on a public IPFS gateway.
+	pub fn placeholder_profile<T: Config>(
+		metadata: BoundedVec<u8, T::StringLimit>,
+	) -> ProjectProfile<T::StringLimit> {
+		// A StringLimit too short for a placeholder leaves it empty rather than halt the chain.
+		let bounded = |bytes: &[u8]| -> BoundedVec<u8, T::StringLimit> {
+			bytes.to_vec().try_into().unwrap_or_default()
+		};
+		let mut profile = ProjectProfile {
+			name: bounded(UNNAMED),
+			website: bounded(&[IPFS_GATEWAY, &metadata[..]].concat()),
+			category: bounded(UNCATEGORISED),
+			token_symbol: None,
+			para_id: None,
+			metadata,
+		};
+		if profile.validate().is_err() {
+			profile.website = bounded(IPFS_GATEWAY);
+		}
+		profile
+	}
+
+	/// Migrate `Projects` to version 3, giving each project a placeholder profile filed under
+	/// `UNCATEGORISED`.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 2 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut projects: Weight = 0;
+			Projects::<T>::translate::<v2::ProjectV2Of<T>, _>(|_, old| {
+				projects = projects.saturating_add(1);
+				Some(ProjectV3 {
+					owner_id: old.owner_id,
+					badge: old.badge,
+					profile: placeholder_profile::<T>(old.metadata),
+					metadata_version: old.metadata_version,
+					proposal_status: old.proposal_status,
+					reward: old.reward,
+					reserve_top_up: old.reserve_top_up,
+					total_user_scores: old.total_user_scores,
+					total_review_score: old.total_review_score,
+					number_of_reviews: old.number_of_reviews,
+					pending_reviews: old.pending_reviews,
+				})
+			});
+			StorageVersion::new(3).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(projects.saturating_add(1), projects.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure_migratable::<T>(2, Projects::<T>::iter_keys().count())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure_migrated::<T>(3)
+		}
+	}
+}
+
+/// Version 4 indexes projects by status and by category.
+pub mod v4 {
+	use super::*;
+
+	/// Fill `ProjectsByStatus`, `ProjectsByCategory` and `Categories` for the existing projects.
+	pub struct MigrateToV4<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 3 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut projects: Weight = 0;
+			for (project_id, project) in v3::Projects::<T>::iter() {
+				projects = projects.saturating_add(1);
+				let category = project.profile.category;
+				<ProjectsByStatus<T>>::insert(project.proposal_status.status, project_id, ());
+				<ProjectsByCategory<T>>::insert(&category, project_id, ());
+				<Categories<T>>::insert(&category, ());
+			}
+			StorageVersion::new(4).put::<Pallet<T>>();
+			let writes = projects.saturating_mul(3).saturating_add(1);
+			T::DbWeight::get().reads_writes(projects.saturating_add(1), writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure_migratable::<T>(3, v3::Projects::<T>::iter_keys().count())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure_migrated::<T>(4)
+		}
+	}
+}
+
+/// Version 5 scores reviews, and totals the scores of projects, per rating dimension. Old
+/// reviews had a single score, so it stands in for every dimension.
+pub mod v5 {
+	use super::*;
+
+	/// `Project` at version 5.
+	#[derive(Encode, Decode)]
+	pub struct ProjectV5<AccountId, Balance, StringLen>
+	where
+		StringLen: Get<u32>,
+	{
+		pub owner_id: AccountId,
+		pub badge: Option<bool>,
+		pub profile: ProjectProfile<StringLen>,
+		pub metadata_version: u32,
+		pub proposal_status: ProposalStatus<StringLen>,
+		pub reward: Balance,
+		pub reserve_top_up: Balance,
+		pub total_user_scores: u32,
+		pub total_review_score: u64,
+		pub dimension_totals: [u64; NUMBER_OF_DIMENSIONS],
+		pub number_of_reviews: u32,
+		pub pending_reviews: u32,
+	}
+	/// Type alias for a project at version 5
+	pub type ProjectV5Of<T> = ProjectV5<
+		<T as frame_system::Config>::AccountId,
+		crate::BalanceOf<T>,
+		<T as Config>::StringLimit,
+	>;
+
+	/// `Projects` at version 5.
+	#[frame_support::storage_alias]
+	pub type Projects<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ProjectID, ProjectV5Of<T>>;
+
+	/// `Review` at version 5.
+	#[derive(Encode, Decode)]
+	pub struct ReviewV5<AccountId, StringLen, CurrencyId>
+	where
+		StringLen: Get<u32>,
+	{
+		pub proposal_status: ProposalStatus<StringLen>,
+		pub user_id: AccountId,
+		pub content: BoundedVec<u8, StringLen>,
+		pub project_id: ProjectID,
+		pub point_snapshot: u32,
+		pub review_score: u8,
+		pub dimension_scores: DimensionScores,
+		pub collateral_currency_id: CurrencyId,
+	}
+	/// Type alias for a review at version 5
+	pub type ReviewV5Of<T> = ReviewV5<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::StringLimit,
+		CurrencyIdOf<T>,
+	>;
+
+	/// `Reviews` at version 5, keyed by author and project.
+	#[frame_support::storage_alias]
+	pub type Reviews<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		ProjectID,
+		ReviewV5Of<T>,
+	>;
+
+	/// Migrate `Projects` and `Reviews` to version 5.
+	pub struct MigrateToV5<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 4 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut items: Weight = 0;
+			Projects::<T>::translate::<v3::ProjectV3Of<T>, _>(|_, old| {
+				items = items.saturating_add(1);
+				Some(ProjectV5 {
+					owner_id: old.owner_id,
+					badge: old.badge,
+					profile: old.profile,
+					metadata_version: old.metadata_version,
+					proposal_status: old.proposal_status,
+					reward: old.reward,
+					reserve_top_up: old.reserve_top_up,
+					total_user_scores: old.total_user_scores,
+					total_review_score: old.total_review_score,
+					dimension_totals: [old.total_review_score; NUMBER_OF_DIMENSIONS],
+					number_of_reviews: old.number_of_reviews,
+					pending_reviews: old.pending_reviews,
+				})
+			});
+			Reviews::<T>::translate::<v0::ReviewV0Of<T>, _>(|_, _, old| {
+				items = items.saturating_add(1);
+				Some(ReviewV5 {
+					proposal_status: old.proposal_status,
+					user_id: old.user_id,
+					content: old.content,
+					project_id: old.project_id,
+					point_snapshot: old.point_snapshot,
+					review_score: old.review_score,
+					dimension_scores: [old.review_score; NUMBER_OF_DIMENSIONS],
+					collateral_currency_id: old.collateral_currency_id,
+				})
+			});
+			StorageVersion::new(5).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(items.saturating_add(1), items.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure_migratable::<T>(4, Projects::<T>::iter_keys().count())?;
+			ensure_migratable::<T>(4, v0::Reviews::<T>::iter_keys().count())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure_migrated::<T>(5)
+		}
+	}
+}
+
+/// Version 6 counts the edits of reviews and whether they were revealed in a review round.
+pub mod v6 {
+	use super::*;
+
+	/// `Review` at version 6.
+	#[derive(Encode, Decode)]
+	pub struct ReviewV6<AccountId, StringLen, CurrencyId>
+	where
+		StringLen: Get<u32>,
+	{
+		pub proposal_status: ProposalStatus<StringLen>,
+		pub user_id: AccountId,
+		pub content: BoundedVec<u8, StringLen>,
+		pub project_id: ProjectID,
+		pub point_snapshot: u32,
+		pub review_score: u8,
+		pub dimension_scores: DimensionScores,
+		pub edit_count: u32,
+		pub revealed: bool,
+		pub collateral_currency_id: CurrencyId,
+	}
+	/// Type alias for a review at version 6
+	pub type ReviewV6Of<T> = ReviewV6<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::StringLimit,
+		CurrencyIdOf<T>,
+	>;
+
+	/// `Reviews` at version 6, keyed by author and project.
+	#[frame_support::storage_alias]
+	pub type Reviews<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		ProjectID,
+		ReviewV6Of<T>,
+	>;
+
+	/// Migrate `Reviews` to version 6. Old reviews were never edited, nor committed in a round.
+	pub struct MigrateToV6<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 5 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut reviews: Weight = 0;
+			Reviews::<T>::translate::<v5::ReviewV5Of<T>, _>(|_, _, old| {
+				reviews = reviews.saturating_add(1);
+				Some(ReviewV6 {
+					proposal_status: old.proposal_status,
+					user_id: old.user_id,
+					content: old.content,
+					project_id: old.project_id,
+					point_snapshot: old.point_snapshot,
+					review_score: old.review_score,
+					dimension_scores: old.dimension_scores,
+					edit_count: 0,
+					revealed: false,
+					collateral_currency_id: old.collateral_currency_id,
+				})
+			});
+			StorageVersion::new(6).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reviews.saturating_add(1), reviews.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure_migratable::<T>(5, v0::Reviews::<T>::iter_keys().count())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure_migrated::<T>(6)
+		}
+	}
+}
+
+/// Version 7 counts the helpfulness votes of reviews and weighs the ratings of projects by the
+/// rank of their reviewers.
+pub mod v7 {
+	use super::*;
+
+	/// `Review` at version 7.
+	#[derive(Encode, Decode)]
+	pub struct ReviewV7<AccountId, StringLen, CurrencyId>
+	where
+		StringLen: Get<u32>,
+	{
+		pub proposal_status: ProposalStatus<StringLen>,
+		pub user_id: AccountId,
+		pub content: BoundedVec<u8, StringLen>,
+		pub project_id: ProjectID,
+		pub point_snapshot: u32,
+		pub review_score: u8,
+		pub dimension_scores: DimensionScores,
+		pub edit_count: u32,
+		pub revealed: bool,
+		pub helpful_votes: u32,
+		pub unhelpful_votes: u32,
+		pub helpfulness_points: u32,
+		pub collateral_currency_id: CurrencyId,
+	}
+	/// Type alias for a review at version 7
+	pub type ReviewV7Of<T> = ReviewV7<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::StringLimit,
+		CurrencyIdOf<T>,
+	>;
+
+	/// `Reviews` at version 7, keyed by author and project.
+	#[frame_support::storage_alias]
+	pub type Reviews<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		ProjectID,
+		ReviewV7Of<T>,
+	>;
+
+	/// Migrate `Projects` and `Reviews` to version 7.
+	///
+	/// Accepted reviews are weighed by the rank their authors had when reviewing.
+	pub struct MigrateToV7<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 6 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut projects: Weight = 0;
+			<Projects<T>>::translate::<v5::ProjectV5Of<T>, _>(|_, old| {
+				projects = projects.saturating_add(1);
+				let mut project = Project::new(old.owner_id, old.profile);
+				project.metadata_version = old.metadata_version;
+				project.proposal_status = old.proposal_status;
+				project.reward = old.reward;
+				project.reserve_top_up = old.reserve_top_up;
+				project.total_user_scores = old.total_user_scores;
+				project.total_review_score = old.total_review_score;
+				project.dimension_totals = old.dimension_totals;
+				project.number_of_reviews = old.number_of_reviews;
+				project.pending_reviews = old.pending_reviews;
+				Some(project)
+			});
+			let mut reviews: Weight = 0;
+			let mut accepted: Weight = 0;
+			Reviews::<T>::translate::<v6::ReviewV6Of<T>, _>(|_, project_id, old| {
+				reviews = reviews.saturating_add(1);
+				if old.proposal_status.status == Status::Accepted {
+					accepted = accepted.saturating_add(1);
+					<Projects<T>>::mutate(project_id, |project| {
+						if let Some(project) = project {
+							project.add_rating_weight(old.review_score, old.point_snapshot);
+						}
+					});
+				}
+				Some(ReviewV7 {
+					proposal_status: old.proposal_status,
+					user_id: old.user_id,
+					content: old.content,
+					project_id: old.project_id,
+					point_snapshot: old.point_snapshot,
+					review_score: old.review_score,
+					dimension_scores: old.dimension_scores,
+					edit_count: old.edit_count,
+					revealed: old.revealed,
+					helpful_votes: 0,
+					unhelpful_votes: 0,
+					helpfulness_points: 0,
+					collateral_currency_id: old.collateral_currency_id,
+				})
+			});
+			StorageVersion::new(7).put::<Pallet<T>>();
+			// Each accepted review updates its project.
+			let items = projects.saturating_add(reviews).saturating_add(accepted);
+			T::DbWeight::get().reads_writes(items.saturating_add(1), items.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure_migratable::<T>(6, <Projects<T>>::iter_keys().count())?;
+			ensure_migratable::<T>(6, v0::Reviews::<T>::iter_keys().count())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure_migrated::<T>(7)
+		}
+	}
+}
+
+/// Version 8 keys reviews by id instead of by author and project.
+pub mod v8 {
+	use super::*;
+
+	/// `Reviews` before version 8, moved aside while the reviews are rekeyed.
+	#[frame_support::storage_alias]
+	pub type OldReviews<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		ProjectID,
+		v7::ReviewV7Of<T>,
+	>;
+
+	/// Migrate `Reviews` to version 8, numbering them from `NextReviewIndex`.
+	pub struct MigrateToV8<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 7 {
+				return T::DbWeight::get().reads(1)
+			}
+			// Old and new reviews share a prefix, so move the old ones aside first.
+			let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+			let old_prefix = storage_prefix(pallet, b"OldReviews");
+			move_prefix(&storage_prefix(pallet, b"Reviews"), &old_prefix);
+			let mut reviews: Weight = 0;
+			let mut review_id = <NextReviewIndex<T>>::get().unwrap_or(1);
+			for (user_id, project_id, old) in OldReviews::<T>::drain() {
+				reviews = reviews.saturating_add(1);
+				if old.proposal_status.status == Status::Proposed {
+					<ProposedReviews<T>>::insert(review_id, ());
+				}
+				<crate::Reviews<T>>::insert(
+					review_id,
+					Review {
+						proposal_status: old.proposal_status,
+						user_id: user_id.clone(),
+						content: old.content,
+						project_id,
+						metadata_version: 0,
+						point_snapshot: old.point_snapshot,
+						review_score: old.review_score,
+						dimension_scores: old.dimension_scores,
+						edit_count: old.edit_count,
+						revealed: old.revealed,
+						helpful_votes: old.helpful_votes,
+						unhelpful_votes: old.unhelpful_votes,
+						helpfulness_points: old.helpfulness_points,
+						supersedes: None,
+						collateral_currency_id: old.collateral_currency_id,
+					},
+				);
+				<LatestReviews<T>>::insert(&user_id, project_id, review_id);
+				review_id = review_id.saturating_add(1);
+			}
+			<NextReviewIndex<T>>::put(review_id);
+			StorageVersion::new(8).put::<Pallet<T>>();
+			// Per review it is moved aside, then rekeyed and indexed.
+			let reads = reviews.saturating_mul(2);
+			let writes = reviews.saturating_mul(5);
+			T::DbWeight::get().reads_writes(reads.saturating_add(2), writes.saturating_add(3))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure_migratable::<T>(7, v0::Reviews::<T>::iter_keys().count())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure_migrated::<T>(8)
+		}
+	}
+}
+
+/// Version 9 counts the proposed reviews of users as open, so they can't deregister with them.
+pub mod v9 {
+	use super::*;
+
+	/// Count each proposed review as open for its author. The users pallet's migration to
+	/// version 1 must run first.
+	pub struct MigrateToV9<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 8 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut proposed: Weight = 0;
+			for review_id in <ProposedReviews<T>>::iter_keys() {
+				proposed = proposed.saturating_add(1);
+				let user_id = match <crate::Reviews<T>>::get(review_id) {
+					Some(review) => review.user_id,
+					None => continue,
+				};
+				if let Some(mut user) = T::UsersOutlet::get_user_by_id(&user_id) {
+					user.open_reviews = user.open_reviews.saturating_add(1);
+					let _ = T::UsersOutlet::update_user(&user_id, user);
+				}
+			}
+			StorageVersion::new(9).put::<Pallet<T>>();
+			// Per proposed review it and its author are read, and the author written.
+			let reads = proposed.saturating_mul(3);
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), proposed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			// Until version 8 the reviews are still keyed by author and project.
+			let reviews = if Pallet::<T>::on_chain_storage_version() < 8 {
+				v0::Reviews::<T>::iter_keys().count()
+			} else {
+				<ProposedReviews<T>>::iter_keys().count()
+			};
+			ensure_migratable::<T>(8, reviews)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure_migrated::<T>(9)
+		}
+	}
+}
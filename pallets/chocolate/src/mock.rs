@@ -1,14 +1,15 @@
 use crate as pallet_chocolate;
 use codec::{Encode, Decode, MaxEncodedLen};
-use frame_support::{parameter_types,traits::{GenesisBuild, Get}, RuntimeDebug};
+use frame_support::{parameter_types,traits::{Get, Nothing}, BoundedVec, RuntimeDebug};
 use frame_system as system;
+use orml_traits::parameter_type_with_key;
 use pallet_users;
 use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	testing::{Header, TestSignature, TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentityLookup},
+	BuildStorage, Perbill,
 };
 
 
@@ -18,7 +19,10 @@ pub use serde::{Deserialize, Serialize};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
-use chocolate_primitives::projects::{Reason, Status};
+use chocolate_primitives::projects::{
+	AccountRelations, DimensionScores, IdentityStatus, IdentityVerifier, ProjectProfile, Reason,
+	Status,
+};
 
 // The runtime is an enum.
 // Configure a mock runtime to test the pallet.
@@ -29,9 +33,11 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		ChocolateModule: pallet_chocolate::{Pallet, Call, Storage, Event<T>},
+		ChocolateModule: pallet_chocolate::{Pallet, Call, Config<T>, Storage, Event<T>, ValidateUnsigned},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		UsersModule: pallet_users::{Pallet, Call, Storage, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+		Currencies: orml_currencies::{Pallet, Call},
 	}
 );
 
@@ -85,12 +91,76 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 	type WeightInfo = ();
 }
+
+/// Currencies known to the mock runtime. Mirrors the runtime's `CurrencyId`.
+#[derive(
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialOrd,
+	Ord,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CurrencyId {
+	Native,
+	DOT,
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> u128 {
+		1
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = u128;
+	type Amount = i128;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type DustRemovalWhitelist = Nothing;
+}
+
+parameter_types! {
+	pub const GetNativeCurrencyId: CurrencyId = CurrencyId::Native;
+}
+
+impl orml_currencies::Config for Test {
+	type MultiCurrency = Tokens;
+	type NativeCurrency = orml_currencies::BasicCurrencyAdapter<Test, Balances, i128, u64>;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type WeightInfo = ();
+}
 // ToDo! temp treasury that has implements unbalanced which stores outer state that can be queried
 
 // This is a mock runtime hence we can't avoid importing users and other deps.
 /// Configure the pallet-users for UserIO trait
 impl pallet_users::Config for Test {
 	type Event = Event;
+	type Currency = Balances;
+	type StringLimit = StringLimit;
+	type RegistrationDeposit = RegistrationDeposit;
+	type DataDepositPerByte = DataDepositPerByte;
+	type VouchBond = VouchBond;
+	type MinVoucherRank = MinVoucherRank;
+	type VouchRankBoost = VouchRankBoost;
+	type PenaltyRankPoints = PenaltyRankPoints;
+	type VouchSlash = VouchSlash;
+	type LinkPrefix = LinkPrefix;
+	type WeightInfo = ();
 }
 
 pub const STRING_LIMIT: u32 = 1_000_000_000;
@@ -105,30 +175,150 @@ impl Get<u32>  for StringLimit {
 		STRING_LIMIT
 	}
 }
+/// Extrinsics submitted by the off-chain worker.
+pub type Extrinsic = TestXt<Call, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		_public: UintAuthorityId,
+		_account: u64,
+		nonce: u64,
+	) -> Option<(Call, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+/// Signs with the `UintAuthorityId` keys set by `UintAuthorityId::set_all_keys`, no keystore needed.
+pub struct TestAuthorityId;
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthorityId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
 parameter_types! {
 	pub const Cap: u128 = 100;
 	pub const UserCollateral: u128 = 10;
+	pub const MaxMetadataHistory: u32 = 2;
+	pub const MaxTeamMembers: u32 = 2;
+	pub const MaxTags: u32 = 3;
+	pub const UnsignedPriority: u64 = 1 << 20;
+	pub const MaxRoundPeriod: u64 = 100;
+	pub const TransferWindow: u64 = 10;
+	pub const MaxReviewEdits: u32 = 2;
+	pub const VoteDeposit: u128 = 5;
+	pub const VotesPerRankPoint: u32 = 2;
+	pub const DataDepositPerByte: u128 = 1;
+	pub const RegistrationDeposit: u128 = REGISTRATION_DEPOSIT;
+	pub const VouchBond: u128 = 100;
+	pub const MinVoucherRank: u32 = 3;
+	pub const VouchRankBoost: u32 = 2;
+	pub const PenaltyRankPoints: u32 = 2;
+	pub const VouchSlash: Perbill = Perbill::from_percent(50);
+	pub const LinkPrefix: &'static [u8] = b"Link to Chocolate account:";
+	pub const BenchmarkCollateralCurrencyId: CurrencyId = CurrencyId::DOT;
+}
+parameter_types! {
+	pub static VerifiedAccounts: Vec<u64> = Vec::new();
+	pub static Delegates: Vec<(u64, u64)> = Vec::new();
 }
+
+/// Identity provider treating `VerifiedAccounts` as verified and everyone else as unknown.
+pub struct MockIdentity;
+impl IdentityVerifier<u64> for MockIdentity {
+	fn identity_status(who: &u64) -> IdentityStatus {
+		if VerifiedAccounts::get().contains(who) {
+			IdentityStatus::Verified
+		} else {
+			IdentityStatus::None
+		}
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_verified(who: &u64) {
+		let mut verified = VerifiedAccounts::get();
+		verified.push(*who);
+		VerifiedAccounts::set(&verified);
+	}
+}
+
+/// Relations treating each `(who, principal)` pair in `Delegates` as a proxy of the principal.
+pub struct MockRelations;
+impl AccountRelations<u64> for MockRelations {
+	fn acts_for(who: &u64, principal: &u64) -> bool {
+		Delegates::get().contains(&(*who, *principal))
+	}
+}
+
 // our configs start here
 impl pallet_chocolate::Config for Test {
 	type Event = Event;
 	// no need to rope in collective pallet. we are enough
 	type ApprovedOrigin = frame_system::EnsureRoot<u64>;
-	// this is simply a pointer to the true implementor,and creator of the currency trait...the balances pallet
-	type Currency = Balances;
+	type Currency = Currencies;
 	type RewardCap = Cap;
 	type UsersOutlet = UsersModule;
 	type UserCollateral = UserCollateral;
 	type StringLimit =  StringLimit;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type MaxMetadataHistory = MaxMetadataHistory;
+	type MaxTeamMembers = MaxTeamMembers;
+	type MaxTags = MaxTags;
+	type VoteDeposit = VoteDeposit;
+	type VotesPerRankPoint = VotesPerRankPoint;
+	type MaxReviewEdits = MaxReviewEdits;
+	type DataDepositPerByte = DataDepositPerByte;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkCollateralCurrencyId = BenchmarkCollateralCurrencyId;
+	type MaxRoundPeriod = MaxRoundPeriod;
+	type IdentityVerifier = MockIdentity;
+	type AccountRelations = MockRelations;
+	type TransferWindow = TransferWindow;
+	type AuthorityId = TestAuthorityId;
+	type UnsignedPriority = UnsignedPriority;
 }
 
+/// Scores per rating dimension used by test reviews.
+pub const SCORES: DimensionScores = [4, 3, 5, 2];
+
+/// Accounts endowed at genesis with both native and DOT balances.
+pub const ENDOWED: [u64; 6] = [1, 2, 3, 4, 5, 6];
+/// Starting balance of each endowed account, per currency.
+pub const ENDOWMENT: u128 = 10_000;
+/// Native deposit reserved when a user is created.
+pub const REGISTRATION_DEPOSIT: u128 = 2;
+
 // construct a test that mocks treasury runtime but prints imbalance value instead
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
 	GenesisConfig {
 		//
-		balances: BalancesConfig { balances: vec![(1, 5000)] },
+		balances: BalancesConfig { balances: ENDOWED.iter().map(|a| (*a, ENDOWMENT)).collect() },
+		tokens: TokensConfig {
+			balances: ENDOWED.iter().map(|a| (*a, CurrencyId::DOT, ENDOWMENT)).collect(),
+		},
+		chocolate_module: ChocolateModuleConfig {
+			init_categories: vec![b"defi".to_vec(), b"wallet".to_vec()],
+			..Default::default()
+		},
 		..Default::default()
 	}
 	.assimilate_storage(&mut t)
@@ -138,19 +328,40 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
-pub fn choc_ext() -> sp_io::TestExternalities {
-	let mut t = pallet_chocolate::GenesisConfig::<Test>::default().build_storage().unwrap();
-
-	pallet_chocolate::GenesisConfig::<Test> {
-		//
-		init_projects: vec![(Status::Accepted, Reason::PassedRequirements)],
-		init_users: vec![1,2,3,4,5,6],
+/// The bytes as a string of the mock runtime.
+pub fn bounded(bytes: &[u8]) -> BoundedVec<u8, StringLimit> {
+	bytes.to_vec().try_into().expect("Should be within string limit")
+}
 
-		..Default::default()
+/// A valid project profile pointing at the given metadata.
+pub fn project_profile(metadata: &[u8]) -> pallet_chocolate::ProjectProfileOf<Test> {
+	ProjectProfile {
+		name: bounded(b"Chocolate"),
+		website: bounded(b"https://chocolate.network"),
+		category: bounded(b"defi"),
+		token_symbol: Some(bounded(b"CHOC")),
+		para_id: Some(2000),
+		metadata: bounded(metadata),
 	}
-	.assimilate_storage(&mut t)
-	.unwrap();
-	let mut ext = sp_io::TestExternalities::new(t);
-	ext.execute_with(|| System::set_block_number(1));
+}
+
+/// Test externalities with one accepted project owned by account 1,
+/// and accepted reviews on it from accounts 2 to 5.
+pub fn choc_ext() -> sp_io::TestExternalities {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		ChocolateModule::initialize_project(
+			1,
+			project_profile(pallet_chocolate::constants::project::METADATA[0]),
+			Status::Accepted,
+			Reason::PassedRequirements,
+		);
+		ChocolateModule::initialize_reviews(vec![
+			(2, CurrencyId::DOT),
+			(3, CurrencyId::DOT),
+			(4, CurrencyId::DOT),
+			(5, CurrencyId::DOT),
+		]);
+	});
 	ext
 }
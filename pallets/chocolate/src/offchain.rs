@@ -0,0 +1,169 @@
+//! Off-chain verification of the content behind project metadata and review CIDs.
+//!
+//! Each block, a node with a `choc` key in its keystore fetches up to
+//! `MAX_FETCHES_PER_BLOCK` CIDs of proposed projects and reviews from the IPFS gateway stored
+//! under [`GATEWAY_KEY`] in its persistent offchain storage, e.g. `http://127.0.0.1:8080/ipfs/`.
+//! Content that is unavailable or does not match its [`ContentSchema`] is flagged for moderators
+//! with a signed-payload unsigned transaction.
+//!
+//! The worker stays idle until the gateway is set, e.g. with the `offchain_localStorageSet` rpc
+//! and the SCALE encoded url.
+use crate::constants::offchain::*;
+use chocolate_primitives::projects::{ContentRef, FlagReason};
+use codec::{Decode, Encode};
+use frame_system::offchain::{SignedPayload, SigningTypes};
+use lite_json::JsonValue;
+use scale_info::TypeInfo;
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+	offchain::{http, storage::StorageValueRef, Duration},
+	RuntimeDebug,
+};
+use sp_std::{str, vec::Vec};
+
+/// Key type of the keys content verifiers sign flags with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"choc");
+/// Persistent offchain storage key of the IPFS gateway url. The CID is appended to it.
+pub const GATEWAY_KEY: &[u8] = b"chocolate::ipfs-gateway";
+/// Prefix of the persistent offchain storage keys marking content as checked.
+const CHECKED_PREFIX: &[u8] = b"chocolate::checked::";
+
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Identifies the sr25519 keys content verifiers sign flags with.
+	pub struct ContentVerifierId;
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for ContentVerifierId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// A flag raised by a content verifier, signed by its `choc` key.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct FlagPayload<Public, BlockNumber, Cid> {
+	/// The flagged content.
+	pub content: ContentRef,
+	/// The CID that was checked. The flag is stale once the content points elsewhere.
+	pub cid: Cid,
+	pub reason: FlagReason,
+	/// Block the content was checked at.
+	pub block_number: BlockNumber,
+	pub public: Public,
+}
+
+impl<T: SigningTypes, Cid: Encode> SignedPayload<T>
+	for FlagPayload<T::Public, T::BlockNumber, Cid>
+{
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
+
+/// The json documents expected behind a CID.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum ContentSchema {
+	/// `{ "name": string, "description": string, .. }`
+	Project,
+	/// `{ "body": string, .. }`
+	Review,
+}
+
+impl ContentSchema {
+	/// Fields that must be present as non-empty strings.
+	fn required_fields(&self) -> &'static [&'static str] {
+		match self {
+			ContentSchema::Project => &["name", "description"],
+			ContentSchema::Review => &["body"],
+		}
+	}
+	/// Whether the document is a json object with all the required fields.
+	pub fn validate(&self, document: &[u8]) -> bool {
+		let fields = match str::from_utf8(document).ok().and_then(|s| lite_json::parse_json(s).ok())
+		{
+			Some(JsonValue::Object(fields)) => fields,
+			_ => return false,
+		};
+		self.required_fields().iter().all(|name| {
+			fields.iter().any(|(key, value)| {
+				key.iter().copied().eq(name.chars()) &&
+					matches!(value, JsonValue::String(s) if !s.is_empty())
+			})
+		})
+	}
+}
+
+/// Outcome of checking a CID against the gateway.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum CheckOutcome {
+	/// The document matches its schema.
+	Valid,
+	/// The gateway answered, but the content should be flagged.
+	Flag(FlagReason),
+	/// The gateway could not be reached or failed to answer. Try again later.
+	Retry,
+}
+
+/// The outcome of a gateway answer that is not a success. Only content the gateway says is
+/// gone is flagged; rate limits, server errors and timeouts are retried.
+pub fn failed_status_outcome(code: u16) -> CheckOutcome {
+	match code {
+		404 | 410 => CheckOutcome::Flag(FlagReason::Unavailable),
+		_ => CheckOutcome::Retry,
+	}
+}
+
+/// Fetch `gateway ++ cid` and check the document against the schema.
+pub fn check_content(gateway: &[u8], cid: &[u8], schema: ContentSchema) -> CheckOutcome {
+	let mut url = gateway.to_vec();
+	url.extend_from_slice(cid);
+	let url = match str::from_utf8(&url) {
+		Ok(url) => url,
+		Err(_) => return CheckOutcome::Retry,
+	};
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+	let pending = match http::Request::get(url).deadline(deadline).send() {
+		Ok(pending) => pending,
+		Err(_) => return CheckOutcome::Retry,
+	};
+	let response = match pending.try_wait(deadline) {
+		Ok(Ok(response)) => response,
+		_ => return CheckOutcome::Retry,
+	};
+	if response.code != 200 {
+		return failed_status_outcome(response.code)
+	}
+	let document: Vec<u8> = response.body().take(MAX_CONTENT_SIZE + 1).collect();
+	if document.len() <= MAX_CONTENT_SIZE && schema.validate(&document) {
+		CheckOutcome::Valid
+	} else {
+		CheckOutcome::Flag(FlagReason::Malformed)
+	}
+}
+
+/// The IPFS gateway url, if the node operator has set one.
+pub fn gateway() -> Option<Vec<u8>> {
+	StorageValueRef::persistent(GATEWAY_KEY).get::<Vec<u8>>().ok().flatten()
+}
+
+fn checked_key(content: &ContentRef, cid: &[u8]) -> Vec<u8> {
+	let mut key = CHECKED_PREFIX.to_vec();
+	(content, cid).encode_to(&mut key);
+	key
+}
+
+/// Whether this node has already checked the CID of the content.
+pub fn is_checked(content: &ContentRef, cid: &[u8]) -> bool {
+	StorageValueRef::persistent(&checked_key(content, cid)).get::<bool>().ok().flatten().is_some()
+}
+
+/// Remember that this node has checked the CID of the content, so it is not fetched again.
+pub fn mark_checked(content: &ContentRef, cid: &[u8]) {
+	StorageValueRef::persistent(&checked_key(content, cid)).set(&true);
+}
@@ -1,19 +1,53 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, assert_err};
+use crate::{
+	constants::project::{METADATA, REVS},
+	migrations::{self, v0, v3, v8},
+	mock::*,
+	offchain::{self, CheckOutcome, FlagPayload, GATEWAY_KEY},
+	Categories, Error, Flags, LatestReviews, MetadataHistory, PendingOwners, ProjectTags,
+	ProjectTeams, Projects, ProposedReviews, RecentTransfers, ReviewCommits, ReviewResponses,
+	ReviewRounds, ReviewVotes, Reviews,
+};
+use chocolate_primitives::projects::{
+	ContentRef, FlagReason, IdentityStatus, ProjectRating, ProjectRole, ProposalStatus, Reason,
+	Status,
+};
+use chocolate_primitives::users::UserIO;
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use sp_core::offchain::{
+	testing, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{BlakeTwo256, Hash, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity},
+};
 
 #[test]
 fn create_project_should_work() {
 	new_test_ext().execute_with(|| {
 		// Dispatch a signed extrinsic.
 		// Try using match expression here. Problem: how to get T for BoundedVec?
-		assert_ok!(ChocolateModule::create_project(Origin::signed(1), [42_u8].to_vec().try_into().expect("Metadata should be within string limit")));
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(1),
+			project_profile(METADATA[1])
+		));
 	});
 }
 
 #[test]
 fn create_project_should_fail() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(ChocolateModule::create_project(Origin::signed(1), [40_u8].to_vec().try_into().expect("Metadata should be within string limit")));
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(1),
+			project_profile(METADATA[4])
+		));
 	});
 }
 
@@ -21,16 +55,1533 @@ fn create_project_should_fail() {
 fn create_review_should_work() {
 	choc_ext().execute_with(|| {
 		// Dispatch a signed extrinsic.
-		assert_ok!(ChocolateModule::create_review(Origin::signed(6), (3,[42_u8].to_vec().try_into().expect("Metadata should be within string limit")), 1_u32));
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, bounded(REVS[0].1)),
+			SCORES,
+			1_u32,
+			CurrencyId::DOT
+		));
 	});
 }
 #[test]
 fn create_review_should_fail() {
 	choc_ext().execute_with(|| {
 		// Based on current genesis config.
-		assert_err!(ChocolateModule::create_review(Origin::signed(1), (3,[40_u8].to_vec().try_into().expect("Metadata should be within string limit")), 1_u32),Error::<Test>::OwnerReviewedProject);
-		assert_err!(ChocolateModule::create_review(Origin::signed(2), (3,[40_u8].to_vec().try_into().expect("Metadata should be within string limit")), 1_u32),Error::<Test>::DuplicateReview);
-		assert_err!(ChocolateModule::create_review(Origin::signed(6), (60,[40_u8].to_vec().try_into().expect("Metadata should be within string limit")), 1_u32),Error::<Test>::ReviewScoreOutOfRange);
+		assert_err!(
+			ChocolateModule::create_review(
+				Origin::signed(1),
+				(3, bounded(REVS[1].1)),
+				SCORES,
+				1_u32,
+				CurrencyId::DOT
+			),
+			Error::<Test>::OwnerReviewedProject
+		);
+		assert_err!(
+			ChocolateModule::create_review(
+				Origin::signed(2),
+				(3, bounded(REVS[1].1)),
+				SCORES,
+				1_u32,
+				CurrencyId::DOT
+			),
+			Error::<Test>::DuplicateReview
+		);
+		assert_err!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(60, bounded(REVS[1].1)),
+				SCORES,
+				1_u32,
+				CurrencyId::DOT
+			),
+			Error::<Test>::ReviewScoreOutOfRange
+		);
+		assert_err!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(3, bounded(REVS[1].1)),
+				[3, 0, 3, 3],
+				1_u32,
+				CurrencyId::DOT
+			),
+			Error::<Test>::ReviewScoreOutOfRange
+		);
+	});
+}
+
+#[test]
+fn reject_review_should_work() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		let scores_before = Projects::<Test>::get(1).unwrap().total_user_scores;
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert!(ProposedReviews::<Test>::contains_key(5));
+		assert_ok!(ChocolateModule::reject_review(
+			Origin::root(),
+			6,
+			1,
+			Reason::InsufficientMetaData
+		));
+		assert!(!ProposedReviews::<Test>::contains_key(5));
+		let review = Reviews::<Test>::get(5).unwrap();
+		assert_eq!(review.proposal_status.status, Status::Rejected);
+		assert_eq!(review.proposal_status.reason, Reason::InsufficientMetaData);
+		let project = Projects::<Test>::get(1).unwrap();
+		assert_eq!((project.pending_reviews, project.total_user_scores), (0, scores_before));
+		assert_eq!(UsersModule::get_user_by_id(&6).unwrap().open_reviews, 0);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::DOT, &6), 0);
+		assert_eq!(Currencies::total_balance(CurrencyId::DOT, &6), ENDOWMENT);
+		System::assert_last_event(crate::Event::ReviewRejected(6, 1, 5).into());
+	});
+}
+
+#[test]
+fn reject_malicious_review_should_slash() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		// Account 1 is established enough to vouch for account 6. MinVoucherRank is 3 in the mock.
+		let owner = UsersModule::get_user_by_id(&1).unwrap();
+		assert_ok!(UsersModule::update_user(
+			&1,
+			chocolate_primitives::users::User { rank_points: 3, ..owner }
+		));
+		assert_ok!(UsersModule::make_user(Origin::signed(6)));
+		assert_ok!(UsersModule::vouch(Origin::signed(1), 6));
+		let voucher_reserved = Currencies::reserved_balance(CurrencyId::Native, &1);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_ok!(ChocolateModule::reject_review(Origin::root(), 6, 1, Reason::Malicious));
+		assert_eq!(Currencies::total_balance(CurrencyId::DOT, &6), ENDOWMENT - 10);
+		// VouchBond is 100 and VouchSlash half of it. The vouch boost of 2 is lost to the penalty.
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &1), voucher_reserved - 50);
+		assert_eq!(UsersModule::get_user_by_id(&6).unwrap().rank_points, 1);
+	});
+}
+
+#[test]
+fn reward_reserve_should_ignore_other_reserves() {
+	choc_ext().execute_with(|| {
+		// Account 6 holds a registration deposit and another bond before owning a project.
+		assert_ok!(UsersModule::make_user(Origin::signed(6)));
+		assert_ok!(Currencies::reserve(CurrencyId::Native, &6, 570));
+		let reserved = Currencies::reserved_balance(CurrencyId::Native, &6);
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(6),
+			project_profile(METADATA[1])
+		));
+		let project = Projects::<Test>::get(2).unwrap();
+		assert_eq!((project.reward, project.reserve_top_up), (Cap::get(), 500));
+		assert_eq!(
+			Currencies::reserved_balance(CurrencyId::Native, &6),
+			reserved + Cap::get() + 500
+		);
+		// Closing releases the project's reserve and nothing else.
+		assert_ok!(ChocolateModule::close_project(Origin::signed(6), 2, 0));
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &6), reserved);
+	});
+}
+
+#[test]
+fn reject_review_should_fail() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		assert_noop!(
+			ChocolateModule::reject_review(Origin::root(), 2, 1, Reason::Malicious),
+			Error::<Test>::RejectingNotProposed
+		);
+		assert_noop!(
+			ChocolateModule::reject_review(Origin::root(), 6, 1, Reason::Malicious),
+			Error::<Test>::ReviewNotFound
+		);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_noop!(
+			ChocolateModule::reject_review(Origin::signed(1), 6, 1, Reason::Malicious),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ChocolateModule::reject_review(Origin::root(), 6, 1, Reason::PassedRequirements),
+			Error::<Test>::InvalidRejectionReason
+		);
+	});
+}
+
+#[test]
+fn update_project_metadata_should_work() {
+	choc_ext().execute_with(|| {
+		let new_meta = bounded(METADATA[2]);
+		assert_ok!(ChocolateModule::update_project_metadata(
+			Origin::signed(1),
+			1,
+			new_meta.clone(),
+			true
+		));
+		let project = Projects::<Test>::get(1).unwrap();
+		assert_eq!(project.metadata(), &new_meta);
+		assert_eq!(project.metadata_version, 1);
+		assert_eq!(project.proposal_status.status, Status::Proposed);
+		let history = MetadataHistory::<Test>::get(1);
+		assert_eq!(history.len(), 1);
+		assert_eq!(&history[0][..], METADATA[0]);
+	});
+}
+
+#[test]
+fn update_project_metadata_keeps_bounded_history() {
+	choc_ext().execute_with(|| {
+		for metadata in &METADATA[1..4] {
+			let meta = bounded(metadata);
+			assert_ok!(ChocolateModule::update_project_metadata(Origin::signed(1), 1, meta, false));
+		}
+		// MaxMetadataHistory is 2 in the mock, the original metadata was dropped.
+		let history = MetadataHistory::<Test>::get(1);
+		let history: Vec<Vec<u8>> = history.iter().map(|m| m.to_vec()).collect();
+		assert_eq!(history, vec![METADATA[1].to_vec(), METADATA[2].to_vec()]);
+		let project = Projects::<Test>::get(1).unwrap();
+		assert_eq!(project.metadata_version, 3);
+		assert_eq!(project.proposal_status.status, Status::Accepted);
+	});
+}
+
+#[test]
+fn update_project_metadata_should_fail() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(METADATA[2]);
+		assert_noop!(
+			ChocolateModule::update_project_metadata(Origin::signed(2), 1, meta.clone(), false),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			ChocolateModule::update_project_metadata(Origin::signed(1), 2, meta, false),
+			Error::<Test>::NoProjectWithId
+		);
+	});
+}
+
+#[test]
+fn invalid_cids_are_rejected() {
+	choc_ext().execute_with(|| {
+		let invalid = bounded(b"not-a-cid");
+		assert_noop!(
+			ChocolateModule::update_project_metadata(Origin::signed(1), 1, invalid.clone(), false),
+			Error::<Test>::InvalidCid
+		);
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(3, invalid),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::InvalidCid
+		);
+		assert_noop!(
+			ChocolateModule::create_project(Origin::signed(6), project_profile(b"Qm")),
+			Error::<Test>::InvalidCid
+		);
+	});
+}
+
+#[test]
+fn close_project_should_work() {
+	choc_ext().execute_with(|| {
+		assert_ok!(ChocolateModule::close_project(Origin::signed(1), 1, 0));
+		let project = Projects::<Test>::get(1).unwrap();
+		assert_eq!(project.proposal_status.status, Status::Closed);
+		assert_eq!(project.reward, 0);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &1), REGISTRATION_DEPOSIT);
+		assert_eq!(UsersModule::get_user_by_id(&1).unwrap().project_id, None);
+		let meta = bounded(REVS[0].1);
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(3, meta),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::ProjectClosed
+		);
+	});
+}
+
+#[test]
+fn close_project_should_clear_project_state() {
+	choc_ext().execute_with(|| {
+		let tag = bounded(b"dex");
+		let tags: crate::TagsOf<Test> = vec![tag].try_into().unwrap();
+		let meta = bounded(METADATA[2]);
+		assert_ok!(ChocolateModule::set_team_member(Origin::signed(1), 1, 6, ProjectRole::Admin));
+		assert_ok!(ChocolateModule::set_project_tags(Origin::signed(1), 1, tags.clone()));
+		assert_ok!(ChocolateModule::update_project_metadata(Origin::signed(1), 1, meta, false));
+		assert_ok!(ChocolateModule::start_review_round(Origin::signed(1), 1, 1, 1));
+		System::set_block_number(4);
+		assert_ok!(ChocolateModule::close_project(Origin::signed(1), 1, 0));
+		assert!(ProjectTeams::<Test>::get(1).is_empty());
+		assert!(ProjectTags::<Test>::get(1).is_empty());
+		assert!(MetadataHistory::<Test>::get(1).is_empty());
+		assert!(ReviewRounds::<Test>::get(1).is_none());
+		// Former team members have no say, and the state can't come back.
+		assert_noop!(
+			ChocolateModule::set_project_tags(Origin::signed(6), 1, tags.clone()),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			ChocolateModule::set_project_tags(Origin::signed(1), 1, tags),
+			Error::<Test>::ProjectClosed
+		);
+	});
+}
+
+#[test]
+fn close_project_should_fail() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		assert_noop!(
+			ChocolateModule::close_project(Origin::signed(2), 1, 0),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_noop!(
+			ChocolateModule::close_project(Origin::signed(1), 1, 0),
+			Error::<Test>::PendingReviews
+		);
+	});
+}
+
+#[test]
+fn open_reviews_should_block_deregister() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &6), REGISTRATION_DEPOSIT);
+		assert_eq!(UsersModule::get_user_by_id(&6).unwrap().open_reviews, 1);
+		assert_noop!(
+			UsersModule::deregister(Origin::signed(6)),
+			pallet_users::Error::<Test>::OpenReviews
+		);
+		assert_noop!(
+			UsersModule::deregister(Origin::signed(1)),
+			pallet_users::Error::<Test>::OwnsProject
+		);
+		assert_ok!(ChocolateModule::accept_review(Origin::root(), 6, 1));
+		assert_eq!(UsersModule::get_user_by_id(&6).unwrap().open_reviews, 0);
+		assert_ok!(UsersModule::deregister(Origin::signed(6)));
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &6), 0);
+	});
+}
+
+#[test]
+fn ownership_transfer_should_work() {
+	choc_ext().execute_with(|| {
+		let reserved = Currencies::reserved_balance(CurrencyId::Native, &1);
+		assert_ok!(ChocolateModule::propose_owner(Origin::signed(1), 1, 6));
+		assert_eq!(PendingOwners::<Test>::get(1), Some(6));
+		assert_ok!(ChocolateModule::accept_ownership(Origin::signed(6), 1));
+		assert_eq!(Projects::<Test>::get(1).unwrap().owner_id, 6);
+		assert_eq!(PendingOwners::<Test>::get(1), None);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &1), REGISTRATION_DEPOSIT);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &6), reserved);
+		assert_eq!(UsersModule::get_user_by_id(&1).unwrap().project_id, None);
+		assert_eq!(UsersModule::get_user_by_id(&6).unwrap().project_id, Some(1));
+	});
+}
+
+#[test]
+fn ownership_transfer_should_fail() {
+	choc_ext().execute_with(|| {
+		assert_noop!(
+			ChocolateModule::propose_owner(Origin::signed(2), 1, 6),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			ChocolateModule::propose_owner(Origin::signed(1), 1, 1),
+			Error::<Test>::AlreadyProjectOwner
+		);
+		assert_ok!(ChocolateModule::propose_owner(Origin::signed(1), 1, 6));
+		assert_noop!(
+			ChocolateModule::accept_ownership(Origin::signed(2), 1),
+			Error::<Test>::NotPendingOwner
+		);
+		// Reviewers can't take over a project while their review of it is pending.
+		let meta = bounded(REVS[0].1);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_noop!(
+			ChocolateModule::accept_ownership(Origin::signed(6), 1),
+			Error::<Test>::HasPendingReview
+		);
+		assert_ok!(ChocolateModule::accept_review(Origin::root(), 6, 1));
+		assert_ok!(ChocolateModule::accept_ownership(Origin::signed(6), 1));
+	});
+}
+
+#[test]
+fn require_identity_should_work() {
+	choc_ext().execute_with(|| {
+		assert_noop!(
+			ChocolateModule::set_identity_requirement(Origin::signed(1), true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ChocolateModule::set_identity_requirement(Origin::root(), true));
+		assert!(ChocolateModule::require_identity());
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(6),
+			project_profile(METADATA[1])
+		));
+		assert_eq!(ChocolateModule::owner_identity(2), Some(IdentityStatus::None));
+		assert_noop!(
+			ChocolateModule::accept_project(Origin::root(), 2),
+			Error::<Test>::UnverifiedOwner
+		);
+		// Accepted projects only pass to verified owners.
+		assert_ok!(ChocolateModule::propose_owner(Origin::signed(1), 1, 5));
+		assert_noop!(
+			ChocolateModule::accept_ownership(Origin::signed(5), 1),
+			Error::<Test>::UnverifiedOwner
+		);
+		VerifiedAccounts::set(&vec![5, 6]);
+		assert_eq!(ChocolateModule::owner_identity(2), Some(IdentityStatus::Verified));
+		assert_ok!(ChocolateModule::accept_project(Origin::root(), 2));
+		assert_ok!(ChocolateModule::accept_ownership(Origin::signed(5), 1));
+		assert_eq!(ChocolateModule::owner_identity(3), None);
+	});
+}
+
+#[test]
+fn team_members_should_work() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(METADATA[2]);
+		assert_ok!(ChocolateModule::set_team_member(Origin::signed(1), 1, 6, ProjectRole::Admin));
+		// Admins manage the team and maintain the project.
+		assert_ok!(ChocolateModule::set_team_member(
+			Origin::signed(6),
+			1,
+			2,
+			ProjectRole::Responder
+		));
+		assert_ok!(ChocolateModule::update_project_metadata(
+			Origin::signed(6),
+			1,
+			meta.clone(),
+			false
+		));
+		assert_noop!(
+			ChocolateModule::update_project_metadata(Origin::signed(2), 1, meta, false),
+			Error::<Test>::InsufficientTeamRole
+		);
+		assert_noop!(
+			ChocolateModule::set_team_member(Origin::signed(1), 1, 3, ProjectRole::Maintainer),
+			Error::<Test>::TooManyTeamMembers
+		);
+		// Members can leave on their own.
+		assert_ok!(ChocolateModule::remove_team_member(Origin::signed(2), 1, 2));
+		assert_eq!(ProjectTeams::<Test>::get(1).to_vec(), vec![(6, ProjectRole::Admin)]);
+	});
+}
+
+#[test]
+fn team_members_cannot_review_their_project() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		assert_ok!(ChocolateModule::set_team_member(
+			Origin::signed(1),
+			1,
+			6,
+			ProjectRole::Responder
+		));
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(3, meta),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::TeamMemberReviewedProject
+		);
+	});
+}
+
+#[test]
+fn pending_reviewers_cannot_join_the_team() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_noop!(
+			ChocolateModule::set_team_member(Origin::signed(1), 1, 6, ProjectRole::Responder),
+			Error::<Test>::HasPendingReview
+		);
+		assert_ok!(ChocolateModule::accept_review(Origin::root(), 6, 1));
+		assert_ok!(ChocolateModule::set_team_member(
+			Origin::signed(1),
+			1,
+			6,
+			ProjectRole::Responder
+		));
+	});
+}
+
+#[test]
+fn owner_proxies_cannot_review_their_project() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		Delegates::set(&vec![(6, 1)]);
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(3, meta.clone()),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::OwnerProxyReviewedProject
+		);
+		Delegates::set(&vec![(1, 6)]);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+	});
+}
+
+#[test]
+fn accounts_funded_by_owner_cannot_review_their_project() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		ChocolateModule::note_transfer(&1, &6);
+		assert_eq!(RecentTransfers::<Test>::get(1, 6), Some(1));
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(3, meta.clone()),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::FundedByOwner
+		);
+		// The window starts at the transfer block.
+		System::set_block_number(TransferWindow::get());
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(3, meta.clone()),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::FundedByOwner
+		);
+		System::set_block_number(1 + TransferWindow::get());
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+	});
+}
+
+#[test]
+fn transfers_before_owning_a_project_should_count() {
+	new_test_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		ChocolateModule::note_transfer(&1, &6);
+		// Many later transfers don't push the record out.
+		for recipient in 7..107 {
+			ChocolateModule::note_transfer(&1, &recipient);
+		}
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(1),
+			project_profile(METADATA[0])
+		));
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(3, meta),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::FundedByOwner
+		);
+	});
+}
+
+#[test]
+fn expired_transfers_should_be_pruned() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		ChocolateModule::note_transfer(&1, &6);
+		// Noting a recipient again restarts the window.
+		System::set_block_number(2);
+		ChocolateModule::note_transfer(&1, &6);
+		assert_eq!(RecentTransfers::<Test>::get(1, 6), Some(2));
+		System::set_block_number(2 + TransferWindow::get());
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_eq!(RecentTransfers::<Test>::get(1, 6), None);
+	});
+}
+
+#[test]
+fn top_up_reward_should_work() {
+	choc_ext().execute_with(|| {
+		let reward = Projects::<Test>::get(1).unwrap().reward;
+		let reserved = Currencies::reserved_balance(CurrencyId::Native, &1);
+		assert_ok!(ChocolateModule::set_team_member(
+			Origin::signed(1),
+			1,
+			6,
+			ProjectRole::Maintainer
+		));
+		assert_ok!(ChocolateModule::top_up_reward(Origin::signed(6), 1, 10));
+		assert_eq!(Projects::<Test>::get(1).unwrap().reward, reward + 10);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &1), reserved + 10);
+		assert_noop!(
+			ChocolateModule::top_up_reward(Origin::signed(1), 1, Cap::get()),
+			Error::<Test>::RewardCapExceeded
+		);
+	});
+}
+
+#[test]
+fn create_project_rejects_invalid_profile() {
+	new_test_ext().execute_with(|| {
+		let mut profile = project_profile(METADATA[1]);
+		profile.name = bounded(b"");
+		assert_noop!(
+			ChocolateModule::create_project(Origin::signed(1), profile),
+			Error::<Test>::InvalidProjectName
+		);
+		let mut profile = project_profile(METADATA[1]);
+		profile.website = bounded(b"ftp://chocolate.network");
+		assert_noop!(
+			ChocolateModule::create_project(Origin::signed(1), profile),
+			Error::<Test>::InvalidWebsite
+		);
+		let mut profile = project_profile(METADATA[1]);
+		profile.category = bounded(b"DeFi");
+		assert_noop!(
+			ChocolateModule::create_project(Origin::signed(1), profile),
+			Error::<Test>::InvalidCategory
+		);
+		let mut profile = project_profile(METADATA[1]);
+		profile.token_symbol = Some(bounded(b"choc"));
+		assert_noop!(
+			ChocolateModule::create_project(Origin::signed(1), profile),
+			Error::<Test>::InvalidTokenSymbol
+		);
+	});
+}
 
+#[test]
+fn categories_should_work() {
+	new_test_ext().execute_with(|| {
+		let nft = bounded(b"nft");
+		let defi = bounded(b"defi");
+		assert_noop!(
+			ChocolateModule::add_category(Origin::signed(1), nft.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ChocolateModule::add_category(Origin::root(), nft.clone()));
+		assert!(Categories::<Test>::contains_key(&nft));
+		assert_noop!(
+			ChocolateModule::add_category(Origin::root(), nft.clone()),
+			Error::<Test>::CategoryExists
+		);
+		let mut profile = project_profile(METADATA[1]);
+		profile.category = bounded(b"games");
+		assert_noop!(
+			ChocolateModule::create_project(Origin::signed(1), profile),
+			Error::<Test>::CategoryNotFound
+		);
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(1),
+			project_profile(METADATA[1])
+		));
+		assert_noop!(
+			ChocolateModule::remove_category(Origin::root(), defi.clone()),
+			Error::<Test>::CategoryInUse
+		);
+		assert_ok!(ChocolateModule::remove_category(Origin::root(), nft));
+		// Closed projects leave their category.
+		assert_ok!(ChocolateModule::close_project(Origin::signed(1), 1, 0));
+		assert!(ChocolateModule::projects_by_category(b"defi".to_vec(), None, 10).is_empty());
+		assert_ok!(ChocolateModule::remove_category(Origin::root(), defi));
+	});
+}
+
+#[test]
+fn categories_are_paginated() {
+	new_test_ext().execute_with(|| {
+		// The mock registers "defi" and "wallet" at genesis.
+		let first = ChocolateModule::categories(None, 1);
+		let second = ChocolateModule::categories(first.first().cloned(), 1);
+		assert_eq!(first.len(), 1);
+		assert_eq!(second.len(), 1);
+		assert_ne!(first, second);
+		assert!(ChocolateModule::categories(second.first().cloned(), 1).is_empty());
+		assert_eq!(ChocolateModule::categories(None, 10).len(), 2);
+	});
+}
+
+#[test]
+fn project_indices_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(1),
+			project_profile(METADATA[1])
+		));
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(2),
+			project_profile(METADATA[2])
+		));
+		let mut profile = project_profile(METADATA[3]);
+		profile.category = bounded(b"wallet");
+		assert_ok!(ChocolateModule::create_project(Origin::signed(3), profile));
+		assert_ok!(ChocolateModule::accept_project(Origin::root(), 2));
+
+		let mut defi = ChocolateModule::projects_by_category(b"defi".to_vec(), None, 10);
+		defi.sort();
+		assert_eq!(defi, vec![1, 2]);
+		assert_eq!(ChocolateModule::projects_by_category(b"wallet".to_vec(), None, 10), vec![3]);
+		let mut proposed = ChocolateModule::projects_by_status(Status::Proposed, None, 10);
+		proposed.sort();
+		assert_eq!(proposed, vec![1, 3]);
+		assert_eq!(ChocolateModule::projects_by_status(Status::Accepted, None, 10), vec![2]);
+
+		// Pages pick up after the cursor.
+		let first = ChocolateModule::projects_by_category(b"defi".to_vec(), None, 1);
+		let second = ChocolateModule::projects_by_category(b"defi".to_vec(), Some(first[0]), 1);
+		assert_eq!(first.len(), 1);
+		assert_eq!(second.len(), 1);
+		assert_ne!(first, second);
+		assert!(
+			ChocolateModule::projects_by_category(b"defi".to_vec(), Some(second[0]), 1).is_empty()
+		);
+	});
+}
+
+#[test]
+fn set_project_tags_should_work() {
+	choc_ext().execute_with(|| {
+		let tags: crate::TagsOf<Test> = vec![bounded(b"dex"), bounded(b"amm")].try_into().unwrap();
+		assert_ok!(ChocolateModule::set_project_tags(Origin::signed(1), 1, tags.clone()));
+		assert_eq!(ProjectTags::<Test>::get(1), tags);
+		let invalid: crate::TagsOf<Test> = vec![bounded(b"Dex")].try_into().unwrap();
+		assert_noop!(
+			ChocolateModule::set_project_tags(Origin::signed(1), 1, invalid),
+			Error::<Test>::InvalidTag
+		);
+		assert_noop!(
+			ChocolateModule::set_project_tags(Origin::signed(2), 1, tags),
+			Error::<Test>::NotProjectOwner
+		);
+	});
+}
+
+#[test]
+fn projects_by_tag_should_follow_tags() {
+	choc_ext().execute_with(|| {
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(6),
+			project_profile(METADATA[1])
+		));
+		let dex: crate::TagsOf<Test> = vec![bounded(b"dex")].try_into().unwrap();
+		assert_ok!(ChocolateModule::set_project_tags(Origin::signed(1), 1, dex.clone()));
+		assert_ok!(ChocolateModule::set_project_tags(Origin::signed(6), 2, dex));
+		let mut tagged = ChocolateModule::projects_by_tag(b"dex".to_vec(), None, 10);
+		tagged.sort();
+		assert_eq!(tagged, vec![1, 2]);
+		let first = ChocolateModule::projects_by_tag(b"dex".to_vec(), None, 1);
+		let second = ChocolateModule::projects_by_tag(b"dex".to_vec(), Some(first[0]), 1);
+		assert_eq!(second.len(), 1);
+		assert_ne!(first, second);
+		// Replacing tags drops the project from its old tags.
+		let amm: crate::TagsOf<Test> = vec![bounded(b"amm")].try_into().unwrap();
+		assert_ok!(ChocolateModule::set_project_tags(Origin::signed(1), 1, amm));
+		assert_eq!(ChocolateModule::projects_by_tag(b"dex".to_vec(), None, 10), vec![2]);
+		assert_eq!(ChocolateModule::projects_by_tag(b"amm".to_vec(), None, 10), vec![1]);
+		// Closed projects leave the index.
+		assert_ok!(ChocolateModule::close_project(Origin::signed(6), 2, 0));
+		assert!(ChocolateModule::projects_by_tag(b"dex".to_vec(), None, 10).is_empty());
+	});
+}
+
+const GATEWAY: &[u8] = b"http://localhost:8080/ipfs/";
+
+/// Run the off-chain worker on two consecutive blocks, against a stand-in gateway serving
+/// `documents` by CID. Each CID must only be fetched once. Returns the submitted extrinsics.
+fn run_offchain_worker(
+	ext: &mut sp_io::TestExternalities,
+	documents: &[(&[u8], &[u8])],
+) -> Vec<Extrinsic> {
+	let (offchain, offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	for (cid, document) in documents {
+		offchain_state.write().expect_request(testing::PendingRequest {
+			method: "GET".into(),
+			uri: String::from_utf8([GATEWAY, *cid].concat()).unwrap(),
+			response: Some(document.to_vec()),
+			sent: true,
+			..Default::default()
+		});
+	}
+	UintAuthorityId::set_all_keys(vec![7]);
+	ext.execute_with(|| {
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			GATEWAY_KEY,
+			&GATEWAY.to_vec().encode(),
+		);
+		ChocolateModule::offchain_worker(1);
+		ChocolateModule::offchain_worker(2);
+	});
+	let transactions = pool_state
+		.read()
+		.transactions
+		.iter()
+		.map(|tx| Extrinsic::decode(&mut &tx[..]).unwrap())
+		.collect();
+	transactions
+}
+
+#[test]
+fn offchain_worker_flags_malformed_content() {
+	let mut ext = choc_ext();
+	ext.execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_ok!(ChocolateModule::add_content_verifier(Origin::root(), 7));
+	});
+	let transactions = run_offchain_worker(&mut ext, &[(REVS[0].1, br#"{"body": 5}"#)]);
+	assert_eq!(transactions.len(), 1);
+	assert_eq!(transactions[0].signature, None);
+	let (payload, signature) = match transactions[0].call.clone() {
+		Call::ChocolateModule(crate::Call::flag_content { payload, signature }) =>
+			(payload, signature),
+		call => panic!("Unexpected call {:?}", call),
+	};
+	assert_eq!(payload.content, ContentRef::Review(5));
+	assert_eq!(payload.reason, FlagReason::Malformed);
+	assert_eq!(payload.public, UintAuthorityId(7));
+	ext.execute_with(|| {
+		let call =
+			crate::Call::flag_content { payload: payload.clone(), signature: signature.clone() };
+		assert!(ChocolateModule::validate_unsigned(TransactionSource::External, &call).is_ok());
+		assert_ok!(ChocolateModule::flag_content(
+			Origin::none(),
+			payload.clone(),
+			signature.clone()
+		));
+		assert_eq!(Flags::<Test>::get(ContentRef::Review(5)), Some(FlagReason::Malformed));
+		assert_noop!(
+			ChocolateModule::flag_content(Origin::none(), payload, signature),
+			Error::<Test>::AlreadyFlagged
+		);
+		assert_ok!(ChocolateModule::clear_flag(Origin::root(), ContentRef::Review(5)));
+		assert_eq!(Flags::<Test>::get(ContentRef::Review(5)), None);
+	});
+}
+
+#[test]
+fn offchain_worker_accepts_valid_content() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		assert_ok!(ChocolateModule::create_project(
+			Origin::signed(1),
+			project_profile(METADATA[1])
+		));
+		assert_ok!(ChocolateModule::add_content_verifier(Origin::root(), 7));
+	});
+	let document = br#"{"name": "Chocolate", "description": "Reviews you can trust"}"#;
+	assert!(run_offchain_worker(&mut ext, &[(METADATA[1], document)]).is_empty());
+}
+
+#[test]
+fn only_missing_content_is_flagged_as_unavailable() {
+	for code in [404, 410] {
+		assert_eq!(
+			offchain::failed_status_outcome(code),
+			CheckOutcome::Flag(FlagReason::Unavailable)
+		);
+	}
+	for code in [408, 429, 500, 502, 503, 504] {
+		assert_eq!(offchain::failed_status_outcome(code), CheckOutcome::Retry);
+	}
+}
+
+#[test]
+fn flag_content_should_fail() {
+	choc_ext().execute_with(|| {
+		let cid = bounded(METADATA[0]);
+		let payload = FlagPayload {
+			content: ContentRef::Project(1),
+			cid,
+			reason: FlagReason::Unavailable,
+			block_number: 1,
+			public: UintAuthorityId(7),
+		};
+		let signature = TestSignature(7, payload.encode());
+		assert_noop!(
+			ChocolateModule::flag_content(Origin::none(), payload.clone(), signature.clone()),
+			Error::<Test>::NotContentVerifier
+		);
+		assert_ok!(ChocolateModule::add_content_verifier(Origin::root(), 7));
+		// Signed by another key.
+		let forged = crate::Call::flag_content {
+			payload: payload.clone(),
+			signature: TestSignature(8, payload.encode()),
+		};
+		let bad_proof: TransactionValidity = InvalidTransaction::BadProof.into();
+		assert_eq!(
+			ChocolateModule::validate_unsigned(TransactionSource::External, &forged),
+			bad_proof
+		);
+		// The project now points at another CID.
+		let new_meta = bounded(METADATA[1]);
+		assert_ok!(ChocolateModule::update_project_metadata(Origin::signed(1), 1, new_meta, false));
+		assert_noop!(
+			ChocolateModule::flag_content(Origin::none(), payload, signature),
+			Error::<Test>::StaleFlag
+		);
+		assert_noop!(
+			ChocolateModule::clear_flag(Origin::root(), ContentRef::Project(1)),
+			Error::<Test>::NotFlagged
+		);
+	});
+}
+
+#[test]
+fn project_rating_should_work() {
+	choc_ext().execute_with(|| {
+		// Genesis reviews score 3, 5, 5 and 3 overall and in every dimension.
+		let rating = ChocolateModule::project_rating(1).unwrap();
+		assert_eq!(
+			rating,
+			ProjectRating {
+				overall: 400,
+				dimensions: [400; 4],
+				weighted_overall: 400,
+				number_of_reviews: 4
+			}
+		);
+		let meta = bounded(REVS[0].1);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		// Pending reviews are not rated.
+		assert_eq!(ChocolateModule::project_rating(1).unwrap().number_of_reviews, 4);
+		assert_ok!(ChocolateModule::accept_review(Origin::root(), 6, 1));
+		let rating = ChocolateModule::project_rating(1).unwrap();
+		assert_eq!(
+			rating,
+			ProjectRating {
+				overall: 380,
+				dimensions: [400, 380, 420, 360],
+				weighted_overall: 380,
+				number_of_reviews: 5
+			}
+		);
+		assert_eq!(ChocolateModule::project_rating(2), None);
+	});
+}
+
+fn commitment(
+	reviewer: u64,
+	project_id: u32,
+	score: u8,
+	content: &BoundedVec<u8, StringLimit>,
+	salt: [u8; 32],
+) -> sp_core::H256 {
+	BlakeTwo256::hash_of(&(reviewer, project_id, score, SCORES, content, salt))
+}
+
+#[test]
+fn commit_reveal_review_should_work() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		let salt = [7_u8; 32];
+		let hash = commitment(6, 1, 3, &meta, salt);
+		assert_noop!(
+			ChocolateModule::commit_review(Origin::signed(6), 1, hash, CurrencyId::DOT),
+			Error::<Test>::NotCommitPhase
+		);
+		assert_noop!(
+			ChocolateModule::start_review_round(Origin::signed(2), 1, 2, 3),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			ChocolateModule::start_review_round(Origin::signed(1), 1, 0, 3),
+			Error::<Test>::InvalidReviewRound
+		);
+		// Account 2 may review again once the metadata changes.
+		let new_meta = bounded(METADATA[1]);
+		assert_ok!(ChocolateModule::update_project_metadata(Origin::signed(1), 1, new_meta, false));
+		// Commits until block 3, reveals until block 6.
+		assert_ok!(ChocolateModule::start_review_round(Origin::signed(1), 1, 2, 3));
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(3, meta.clone()),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::ReviewRoundActive
+		);
+		assert_ok!(ChocolateModule::commit_review(Origin::signed(6), 1, hash, CurrencyId::DOT));
+		assert_noop!(
+			ChocolateModule::commit_review(Origin::signed(6), 1, hash, CurrencyId::DOT),
+			Error::<Test>::AlreadyCommitted
+		);
+		// Account 2 copies the commitment.
+		assert_ok!(ChocolateModule::commit_review(Origin::signed(2), 1, hash, CurrencyId::DOT));
+		assert_noop!(
+			ChocolateModule::reveal_review(Origin::signed(6), 1, (3, meta.clone()), SCORES, salt),
+			Error::<Test>::NotRevealPhase
+		);
+		// Unrevealed reviews do not count.
+		let project = Projects::<Test>::get(1).unwrap();
+		assert_eq!(project.pending_reviews, 0);
+		let total_user_scores = project.total_user_scores;
+		System::set_block_number(4);
+		assert_noop!(
+			ChocolateModule::commit_review(Origin::signed(3), 1, hash, CurrencyId::DOT),
+			Error::<Test>::NotCommitPhase
+		);
+		assert_noop!(
+			ChocolateModule::reveal_review(Origin::signed(6), 1, (4, meta.clone()), SCORES, salt),
+			Error::<Test>::CommitmentMismatch
+		);
+		// The copied commitment can't be opened with the revealed review.
+		assert_noop!(
+			ChocolateModule::reveal_review(Origin::signed(2), 1, (3, meta.clone()), SCORES, salt),
+			Error::<Test>::CommitmentMismatch
+		);
+		assert_ok!(ChocolateModule::reveal_review(Origin::signed(6), 1, (3, meta), SCORES, salt));
+		assert!(!ReviewCommits::<Test>::contains_key(6, 1));
+		// The commit is now the open review.
+		assert_eq!(UsersModule::get_user_by_id(&6).unwrap().open_reviews, 1);
+		assert_eq!(Reviews::<Test>::get(5).unwrap().dimension_scores, SCORES);
+		let project = Projects::<Test>::get(1).unwrap();
+		assert_eq!(project.pending_reviews, 1);
+		let rank_points = UsersModule::get_user_by_id(&6).unwrap().rank_points;
+		assert_eq!(project.total_user_scores, total_user_scores + rank_points);
+		// Revealed reviews stay as revealed, even once the round is over.
+		System::set_block_number(7);
+		assert_noop!(
+			ChocolateModule::edit_review(Origin::signed(6), 1, (5, meta.clone()), SCORES),
+			Error::<Test>::ReviewNotEditable
+		);
+		assert_ok!(ChocolateModule::accept_review(Origin::root(), 6, 1));
+	});
+}
+
+#[test]
+fn unrevealed_commits_are_forfeited() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		// Commits until block 2, reveals until block 3.
+		assert_ok!(ChocolateModule::start_review_round(Origin::signed(1), 1, 1, 1));
+		assert_ok!(ChocolateModule::commit_review(
+			Origin::signed(6),
+			1,
+			commitment(6, 1, 3, &meta, [0; 32]),
+			CurrencyId::DOT
+		));
+		assert_noop!(
+			ChocolateModule::close_project(Origin::signed(1), 1, 0),
+			Error::<Test>::ReviewRoundActive
+		);
+		// The commit is open until forfeited, so the reviewer can't deregister.
+		assert_eq!(UsersModule::get_user_by_id(&6).unwrap().open_reviews, 1);
+		assert_noop!(
+			UsersModule::deregister(Origin::signed(6)),
+			pallet_users::Error::<Test>::OpenReviews
+		);
+		System::set_block_number(3);
+		assert_noop!(
+			ChocolateModule::forfeit_commit(Origin::signed(2), 6, 1),
+			Error::<Test>::RevealPhaseNotOver
+		);
+		System::set_block_number(4);
+		assert_noop!(
+			ChocolateModule::reveal_review(Origin::signed(6), 1, (3, meta), SCORES, [0; 32]),
+			Error::<Test>::NotRevealPhase
+		);
+		assert_ok!(ChocolateModule::forfeit_commit(Origin::signed(2), 6, 1));
+		assert!(!ReviewCommits::<Test>::contains_key(6, 1));
+		assert_eq!(Currencies::total_balance(CurrencyId::DOT, &6), ENDOWMENT - 10);
+		assert_noop!(
+			ChocolateModule::forfeit_commit(Origin::signed(2), 6, 1),
+			Error::<Test>::NoCommit
+		);
+		assert_eq!(UsersModule::get_user_by_id(&6).unwrap().open_reviews, 0);
+		assert_ok!(UsersModule::deregister(Origin::signed(6)));
+		assert_ok!(ChocolateModule::close_project(Origin::signed(1), 1, 0));
+	});
+}
+
+#[test]
+fn edit_review_should_work() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		let fixed = bounded(REVS[1].1);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_noop!(
+			ChocolateModule::edit_review(Origin::signed(6), 1, (6, fixed.clone()), SCORES),
+			Error::<Test>::ReviewScoreOutOfRange
+		);
+		Flags::<Test>::insert(ContentRef::Review(5), FlagReason::Unavailable);
+		assert_ok!(ChocolateModule::edit_review(Origin::signed(6), 1, (4, fixed.clone()), [5; 4]));
+		// The flag was for the old content.
+		assert_eq!(Flags::<Test>::get(ContentRef::Review(5)), None);
+		let review = Reviews::<Test>::get(5).unwrap();
+		assert_eq!(
+			(review.review_score, review.content, review.dimension_scores),
+			(4, fixed.clone(), [5; 4])
+		);
+		assert_eq!(review.edit_count, 1);
+		// MaxReviewEdits is 2 in the mock.
+		assert_ok!(ChocolateModule::edit_review(Origin::signed(6), 1, (4, fixed.clone()), SCORES));
+		assert_noop!(
+			ChocolateModule::edit_review(Origin::signed(6), 1, (4, fixed), SCORES),
+			Error::<Test>::TooManyEdits
+		);
+	});
+}
+
+#[test]
+fn accepted_reviews_cannot_be_edited() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[1].1);
+		assert_noop!(
+			ChocolateModule::edit_review(Origin::signed(2), 1, (5, meta.clone()), SCORES),
+			Error::<Test>::ReviewNotEditable
+		);
+		assert_noop!(
+			ChocolateModule::edit_review(Origin::signed(6), 1, (5, meta), SCORES),
+			Error::<Test>::ReviewNotFound
+		);
+	});
+}
+
+#[test]
+fn vote_review_should_work() {
+	choc_ext().execute_with(|| {
+		// Genesis reviews 1 to 4, by accounts 2 to 5, scored 3, 5, 5 and 3, each with a weight of one rank point.
+		assert_ok!(ChocolateModule::vote_review(Origin::signed(3), 1, true));
+		assert_eq!(UsersModule::get_user_by_id(&2).unwrap().rank_points, 2);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &3), 5 + REGISTRATION_DEPOSIT);
+		// VotesPerRankPoint is 2 in the mock.
+		assert_ok!(ChocolateModule::vote_review(Origin::signed(4), 1, true));
+		assert_eq!(UsersModule::get_user_by_id(&2).unwrap().rank_points, 3);
+		assert_eq!(Reviews::<Test>::get(1).unwrap().helpfulness_points, 1);
+		assert_eq!(ChocolateModule::project_rating(1).unwrap().weighted_overall, 380);
+		assert_ok!(ChocolateModule::vote_review(Origin::signed(5), 1, false));
+		assert_eq!(UsersModule::get_user_by_id(&2).unwrap().rank_points, 2);
+		assert_eq!(ChocolateModule::project_rating(1).unwrap().weighted_overall, 400);
+		assert_ok!(ChocolateModule::retract_vote(Origin::signed(5), 1));
+		assert!(!ReviewVotes::<Test>::contains_key(1, 5));
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &5), REGISTRATION_DEPOSIT);
+		assert_eq!(UsersModule::get_user_by_id(&2).unwrap().rank_points, 3);
+		let review = Reviews::<Test>::get(1).unwrap();
+		assert_eq!((review.helpful_votes, review.unhelpful_votes), (2, 0));
+	});
+}
+
+#[test]
+fn votes_should_outlive_the_author() {
+	choc_ext().execute_with(|| {
+		assert_ok!(ChocolateModule::vote_review(Origin::signed(3), 1, true));
+		assert_ok!(UsersModule::deregister(Origin::signed(2)));
+		assert_ok!(ChocolateModule::vote_review(Origin::signed(4), 1, true));
+		assert_eq!(Reviews::<Test>::get(1).unwrap().helpfulness_points, 1);
+		assert_eq!(ChocolateModule::project_rating(1).unwrap().weighted_overall, 380);
+		assert_ok!(ChocolateModule::retract_vote(Origin::signed(3), 1));
+		assert_ok!(ChocolateModule::retract_vote(Origin::signed(4), 1));
+		assert_eq!(Reviews::<Test>::get(1).unwrap().helpfulness_points, 0);
+		assert_eq!(ChocolateModule::project_rating(1).unwrap().weighted_overall, 400);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &3), REGISTRATION_DEPOSIT);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &4), REGISTRATION_DEPOSIT);
+		assert_eq!(UsersModule::get_user_by_id(&2), None);
+	});
+}
+
+#[test]
+fn vote_review_should_fail() {
+	choc_ext().execute_with(|| {
+		assert_noop!(
+			ChocolateModule::vote_review(Origin::signed(6), 1, true),
+			Error::<Test>::NotRegisteredUser
+		);
+		assert_noop!(
+			ChocolateModule::vote_review(Origin::signed(2), 1, true),
+			Error::<Test>::SelfVote
+		);
+		assert_ok!(ChocolateModule::vote_review(Origin::signed(3), 1, true));
+		assert_noop!(
+			ChocolateModule::vote_review(Origin::signed(3), 1, false),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_noop!(ChocolateModule::retract_vote(Origin::signed(4), 1), Error::<Test>::NoVote);
+		let meta = bounded(REVS[0].1);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_noop!(
+			ChocolateModule::vote_review(Origin::signed(3), 5, true),
+			Error::<Test>::ReviewNotAccepted
+		);
+	});
+}
+
+#[test]
+fn respond_to_review_should_work() {
+	choc_ext().execute_with(|| {
+		let response = bounded(REVS[0].1);
+		let reserved = Currencies::reserved_balance(CurrencyId::Native, &1);
+		assert_ok!(ChocolateModule::respond_to_review(Origin::signed(1), 1, response.clone()));
+		// DataDepositPerByte is 1 in the mock.
+		let deposit = response.len() as u128;
+		assert_eq!(ReviewResponses::<Test>::get(1, 1), Some((response, 1, deposit)));
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &1), reserved + deposit);
+		// Replacing a response swaps the deposit.
+		let shorter = bounded(METADATA[1]);
+		assert_ok!(ChocolateModule::respond_to_review(Origin::signed(1), 1, shorter.clone()));
+		let deposit = shorter.len() as u128;
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &1), reserved + deposit);
+		assert_ok!(ChocolateModule::remove_review_response(Origin::signed(1), 1));
+		assert!(!ReviewResponses::<Test>::contains_key(1, 1));
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &1), reserved);
+	});
+}
+
+#[test]
+fn team_responses_should_release_to_the_responder() {
+	choc_ext().execute_with(|| {
+		let response = bounded(REVS[0].1);
+		let deposit = response.len() as u128;
+		assert_ok!(ChocolateModule::set_team_member(
+			Origin::signed(1),
+			1,
+			6,
+			ProjectRole::Maintainer
+		));
+		assert_noop!(
+			ChocolateModule::respond_to_review(Origin::signed(6), 1, response.clone()),
+			Error::<Test>::InsufficientTeamRole
+		);
+		assert_ok!(ChocolateModule::set_team_member(
+			Origin::signed(1),
+			1,
+			6,
+			ProjectRole::Responder
+		));
+		let reserved = Currencies::reserved_balance(CurrencyId::Native, &6);
+		assert_ok!(ChocolateModule::respond_to_review(Origin::signed(6), 1, response.clone()));
+		assert_eq!(ReviewResponses::<Test>::get(1, 1), Some((response, 6, deposit)));
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &6), reserved + deposit);
+		// The owner removing it releases the deposit to the responder.
+		let owner_reserved = Currencies::reserved_balance(CurrencyId::Native, &1);
+		assert_ok!(ChocolateModule::remove_review_response(Origin::signed(1), 1));
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &6), reserved);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &1), owner_reserved);
+	});
+}
+
+#[test]
+fn close_project_should_release_responses() {
+	choc_ext().execute_with(|| {
+		let response = bounded(REVS[0].1);
+		assert_ok!(ChocolateModule::set_team_member(
+			Origin::signed(1),
+			1,
+			6,
+			ProjectRole::Responder
+		));
+		let reserved = Currencies::reserved_balance(CurrencyId::Native, &6);
+		assert_ok!(ChocolateModule::respond_to_review(Origin::signed(1), 1, response.clone()));
+		assert_ok!(ChocolateModule::respond_to_review(Origin::signed(6), 2, response.clone()));
+		assert_noop!(
+			ChocolateModule::close_project(Origin::signed(1), 1, 1),
+			Error::<Test>::TooManyResponses
+		);
+		assert_ok!(ChocolateModule::close_project(Origin::signed(1), 1, 2));
+		assert_eq!(ReviewResponses::<Test>::iter_prefix(1).count(), 0);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &1), REGISTRATION_DEPOSIT);
+		assert_eq!(Currencies::reserved_balance(CurrencyId::Native, &6), reserved);
+		// Nothing would release the deposits of later responses.
+		assert_noop!(
+			ChocolateModule::respond_to_review(Origin::signed(1), 3, response),
+			Error::<Test>::ProjectClosed
+		);
+	});
+}
+
+#[test]
+fn respond_to_review_should_fail() {
+	choc_ext().execute_with(|| {
+		let response = bounded(REVS[0].1);
+		let invalid = bounded(b"not a cid");
+		assert_noop!(
+			ChocolateModule::respond_to_review(Origin::signed(2), 1, response.clone()),
+			Error::<Test>::NotProjectOwner
+		);
+		assert_noop!(
+			ChocolateModule::respond_to_review(Origin::signed(1), 5, response.clone()),
+			Error::<Test>::ReviewNotFound
+		);
+		assert_noop!(
+			ChocolateModule::respond_to_review(Origin::signed(1), 1, invalid),
+			Error::<Test>::InvalidCid
+		);
+		assert_noop!(
+			ChocolateModule::remove_review_response(Origin::signed(1), 1),
+			Error::<Test>::NoResponse
+		);
+	});
+}
+
+#[test]
+fn re_review_after_metadata_update_should_work() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		let new_meta = bounded(METADATA[1]);
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(6),
+			(3, meta.clone()),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(2),
+				(4, meta.clone()),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::DuplicateReview
+		);
+		assert_ok!(ChocolateModule::update_project_metadata(Origin::signed(1), 1, new_meta, false));
+		// Genesis review 1 by account 2 predates the update.
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(2),
+			(4, meta.clone()),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_eq!(LatestReviews::<Test>::get(2, 1), Some(6));
+		assert_eq!(Reviews::<Test>::get(6).unwrap().metadata_version, 1);
+		assert_eq!(Reviews::<Test>::get(1).unwrap().metadata_version, 0);
+		// Review 5 by account 6 is still pending.
+		assert_noop!(
+			ChocolateModule::create_review(
+				Origin::signed(6),
+				(4, meta),
+				SCORES,
+				1,
+				CurrencyId::DOT
+			),
+			Error::<Test>::DuplicateReview
+		);
+	});
+}
+
+#[test]
+fn accepted_re_review_should_replace_the_old_review() {
+	choc_ext().execute_with(|| {
+		let meta = bounded(REVS[0].1);
+		let new_meta = bounded(METADATA[1]);
+		let user_scores = Projects::<Test>::get(1).unwrap().total_user_scores;
+		let old_snapshot = Reviews::<Test>::get(1).unwrap().point_snapshot;
+		assert_ok!(ChocolateModule::update_project_metadata(Origin::signed(1), 1, new_meta, false));
+		// Genesis review 1 by account 2 scored 3, the others 5, 5 and 3.
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(2),
+			(4, meta),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		let new_snapshot = Reviews::<Test>::get(5).unwrap().point_snapshot;
+		assert_eq!(Reviews::<Test>::get(5).unwrap().supersedes, Some(1));
+		// The old review counts until the new one is accepted.
+		assert_eq!(ChocolateModule::project_rating(1).unwrap().overall, 400);
+		assert_ok!(ChocolateModule::accept_review(Origin::root(), 2, 1));
+		System::assert_last_event(crate::Event::ReviewAccepted(2, 1, 5).into());
+		let rating = ChocolateModule::project_rating(1).unwrap();
+		assert_eq!((rating.overall, rating.number_of_reviews), (425, 4));
+		let project = Projects::<Test>::get(1).unwrap();
+		assert_eq!(project.total_user_scores, user_scores - old_snapshot + new_snapshot);
+		// Votes on the superseded review still earn rank but no longer move the rating.
+		let weighted = rating.weighted_overall;
+		assert_ok!(ChocolateModule::vote_review(Origin::signed(3), 1, true));
+		assert_ok!(ChocolateModule::vote_review(Origin::signed(4), 1, true));
+		assert_eq!(Reviews::<Test>::get(1).unwrap().helpfulness_points, 1);
+		assert_eq!(ChocolateModule::project_rating(1).unwrap().weighted_overall, weighted);
+	});
+}
+
+#[test]
+fn migrations_should_work() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<ChocolateModule>();
+		let status = |status| ProposalStatus { status, reason: Reason::PassedRequirements };
+		// Project 1 by account 1 reserved the reward and the existential deposit.
+		assert_ok!(Currencies::reserve(CurrencyId::Native, &1, Cap::get() + 500));
+		let old_project = v0::ProjectV0 {
+			owner_id: 1u64,
+			badge: None,
+			metadata: bounded(METADATA[0]),
+			proposal_status: status(Status::Accepted),
+			reward: Cap::get(),
+			total_user_scores: 3,
+			total_review_score: 4,
+			number_of_reviews: 1,
+		};
+		unhashed::put(&Projects::<Test>::hashed_key_for(1), &old_project);
+		// Account 2's review is accepted, account 3's still proposed.
+		assert_ok!(UsersModule::make_user(Origin::signed(3)));
+		let old_review = |user_id, score, snapshot, proposal_status| v0::ReviewV0 {
+			proposal_status,
+			user_id,
+			content: bounded(REVS[0].1),
+			project_id: 1,
+			point_snapshot: snapshot,
+			review_score: score,
+			collateral_currency_id: CurrencyId::DOT,
+		};
+		v0::Reviews::<Test>::insert(2, 1, old_review(2, 4, 2, status(Status::Accepted)));
+		v0::Reviews::<Test>::insert(3, 1, old_review(3, 2, 1, status(Status::Proposed)));
+		migrations::Migrations::<Test>::on_runtime_upgrade();
+		assert_eq!(
+			ChocolateModule::on_chain_storage_version(),
+			ChocolateModule::current_storage_version()
+		);
+		let project = Projects::<Test>::get(1).unwrap();
+		assert_eq!(project.metadata(), &bounded(METADATA[0]));
+		assert_eq!(project.profile().category, bounded(v3::UNCATEGORISED));
+		assert_eq!(project.profile().validate(), Ok(()));
+		assert_eq!(
+			(project.reward, project.reserve_top_up, project.pending_reviews),
+			(Cap::get(), 500, 1)
+		);
+		assert_eq!(
+			ChocolateModule::project_rating(1),
+			Some(ProjectRating {
+				overall: 400,
+				dimensions: [400; 4],
+				weighted_overall: 400,
+				number_of_reviews: 1
+			})
+		);
+		assert!(Categories::<Test>::contains_key(bounded(v3::UNCATEGORISED)));
+		assert_eq!(
+			ChocolateModule::projects_by_category(v3::UNCATEGORISED.to_vec(), None, 10),
+			vec![1]
+		);
+		assert_eq!(ChocolateModule::projects_by_status(Status::Accepted, None, 10), vec![1]);
+		let accepted = LatestReviews::<Test>::get(2, 1).unwrap();
+		let proposed = LatestReviews::<Test>::get(3, 1).unwrap();
+		let review = Reviews::<Test>::get(accepted).unwrap();
+		assert_eq!((review.user_id, review.review_score, review.dimension_scores), (2, 4, [4; 4]));
+		assert_eq!(
+			Reviews::<Test>::get(proposed).unwrap().proposal_status.status,
+			Status::Proposed
+		);
+		assert!(ProposedReviews::<Test>::contains_key(proposed));
+		assert!(!ProposedReviews::<Test>::contains_key(accepted));
+		assert_eq!(UsersModule::get_user_by_id(&3).unwrap().open_reviews, 1);
+		assert_eq!(v8::OldReviews::<Test>::iter().count(), 0);
+		// New reviews carry on after the migrated ones.
+		assert_ok!(ChocolateModule::create_review(
+			Origin::signed(4),
+			(4, bounded(REVS[0].1)),
+			SCORES,
+			1,
+			CurrencyId::DOT
+		));
+		assert_eq!(LatestReviews::<Test>::get(4, 1), Some(3));
 	});
 }
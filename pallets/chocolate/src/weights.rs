@@ -0,0 +1,694 @@
+//! Weights for pallet_chocolate
+//!
+//! Not benchmark output. Each call is charged `DbWeight` for the storage it touches in its
+//! worst case, as listed above it, plus the flat `EXECUTION` allowance for everything else.
+//! Replace this file with the output of the benchmarks in `benchmarking.rs`, generated on
+//! reference hardware from the repository root with:
+//!
+//! ./scripts/benchmark.sh chocolate
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Flat allowance for the computation of a call, on top of its storage accesses.
+/// Deliberately generous until it is replaced by measured base weights.
+pub const EXECUTION: Weight = 50_000_000;
+/// Allowance for validating each tag in `set_project_tags`.
+pub const PER_TAG: Weight = 1_000_000;
+
+/// Weight functions needed for pallet_chocolate.
+pub trait WeightInfo {
+	fn create_project() -> Weight;
+	fn create_review() -> Weight;
+	fn edit_review() -> Weight;
+	fn vote_review() -> Weight;
+	fn respond_to_review() -> Weight;
+	fn remove_review_response() -> Weight;
+	fn retract_vote() -> Weight;
+	fn accept_review() -> Weight;
+	fn reject_review() -> Weight;
+	fn accept_project() -> Weight;
+	fn update_project_metadata() -> Weight;
+	fn close_project(r: u32, t: u32, ) -> Weight;
+	fn propose_owner() -> Weight;
+	fn accept_ownership() -> Weight;
+	fn set_team_member() -> Weight;
+	fn remove_team_member() -> Weight;
+	fn top_up_reward() -> Weight;
+	fn add_category() -> Weight;
+	fn remove_category() -> Weight;
+	fn set_project_tags(t: u32, ) -> Weight;
+	fn start_review_round() -> Weight;
+	fn commit_review() -> Weight;
+	fn reveal_review() -> Weight;
+	fn forfeit_commit() -> Weight;
+	fn add_content_verifier() -> Weight;
+	fn remove_content_verifier() -> Weight;
+	fn flag_content() -> Weight;
+	fn clear_flag() -> Weight;
+	fn note_transfer() -> Weight;
+	fn set_identity_requirement() -> Weight;
+}
+
+/// Weights for pallet_chocolate, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Chocolate Categories (r:1 w:0)
+	// Storage: Chocolate NextProjectIndex (r:1 w:1)
+	// Storage: Users Users (r:2 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate ProjectsByStatus (r:0 w:1)
+	// Storage: Chocolate ProjectsByCategory (r:0 w:1)
+	// Storage: Chocolate Projects (r:0 w:1)
+	fn create_project() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate RecentTransfers (r:1 w:1)
+	// Storage: Identity SuperOf (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Chocolate LatestReviews (r:1 w:1)
+	// Storage: Chocolate ReviewRounds (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Users Users (r:2 w:1)
+	// Storage: Chocolate NextReviewIndex (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Chocolate Reviews (r:0 w:1)
+	// Storage: Chocolate ProposedReviews (r:0 w:1)
+	fn create_review() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Chocolate ReviewRounds (r:1 w:0)
+	// Storage: Users Users (r:1 w:0)
+	// Storage: Chocolate Flags (r:0 w:1)
+	fn edit_review() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Users Users (r:2 w:1)
+	// Storage: Chocolate ReviewVotes (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	fn vote_review() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Chocolate Reviews (r:1 w:0)
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ReviewResponses (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn respond_to_review() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Chocolate Reviews (r:1 w:0)
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ReviewResponses (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	fn remove_review_response() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Chocolate ReviewVotes (r:1 w:1)
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	fn retract_vote() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: Chocolate ProposedReviews (r:0 w:1)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	fn accept_review() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Users Vouches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate ProposedReviews (r:0 w:1)
+	fn reject_review() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Chocolate ProjectsByStatus (r:0 w:2)
+	// Storage: Chocolate RequireIdentity (r:1 w:0)
+	fn accept_project() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate MetadataHistory (r:1 w:1)
+	// Storage: Chocolate ProjectsByStatus (r:0 w:2)
+	fn update_project_metadata() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ReviewRounds (r:1 w:1)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:0)
+	// Storage: Chocolate ProjectsByStatus (r:0 w:2)
+	// Storage: Chocolate PendingOwners (r:0 w:1)
+	// Storage: Chocolate ProjectsByCategory (r:0 w:1)
+	// Storage: Chocolate ReviewResponses (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Chocolate ProjectTags (r:1 w:1)
+	// Storage: Chocolate ProjectsByTag (r:0 w:1)
+	// Storage: Chocolate ProjectTeams (r:0 w:1)
+	// Storage: Chocolate MetadataHistory (r:0 w:1)
+	fn close_project(r: u32, t: u32, ) -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate PendingOwners (r:0 w:1)
+	fn propose_owner() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate PendingOwners (r:1 w:1)
+	// Storage: Users Users (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Chocolate ProjectTeams (r:1 w:1)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	// Storage: Chocolate ReviewCommits (r:1 w:0)
+	// Storage: Chocolate RequireIdentity (r:1 w:0)
+	fn accept_ownership() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:1)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	// Storage: Chocolate ReviewCommits (r:1 w:0)
+	fn set_team_member() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:1)
+	fn remove_team_member() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn top_up_reward() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Chocolate Categories (r:1 w:1)
+	fn add_category() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Categories (r:1 w:1)
+	// Storage: Chocolate ProjectsByCategory (r:1 w:0)
+	fn remove_category() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate ProjectTags (r:1 w:1)
+	// Storage: Chocolate ProjectsByTag (r:0 w:2)
+	fn set_project_tags(t: u32, ) -> Weight {
+		EXECUTION
+			.saturating_add(PER_TAG.saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate ReviewRounds (r:1 w:1)
+	fn start_review_round() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ReviewRounds (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate RecentTransfers (r:1 w:1)
+	// Storage: Identity SuperOf (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate ReviewCommits (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Users Users (r:1 w:1)
+	fn commit_review() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Chocolate ReviewCommits (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate RecentTransfers (r:1 w:1)
+	// Storage: Identity SuperOf (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Chocolate LatestReviews (r:1 w:1)
+	// Storage: Users Users (r:3 w:2)
+	// Storage: Chocolate NextReviewIndex (r:1 w:1)
+	// Storage: Chocolate Reviews (r:0 w:1)
+	// Storage: Chocolate ProposedReviews (r:0 w:1)
+	fn reveal_review() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Chocolate ReviewCommits (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	// Storage: Users Users (r:1 w:1)
+	fn forfeit_commit() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Chocolate ContentVerifiers (r:1 w:1)
+	fn add_content_verifier() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate ContentVerifiers (r:1 w:1)
+	fn remove_content_verifier() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate ContentVerifiers (r:1 w:0)
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate Flags (r:1 w:1)
+	fn flag_content() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Flags (r:1 w:1)
+	fn clear_flag() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate RecentTransfers (r:0 w:1)
+	fn note_transfer() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate RequireIdentity (r:0 w:1)
+	fn set_identity_requirement() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Chocolate Categories (r:1 w:0)
+	// Storage: Chocolate NextProjectIndex (r:1 w:1)
+	// Storage: Users Users (r:2 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate ProjectsByStatus (r:0 w:1)
+	// Storage: Chocolate ProjectsByCategory (r:0 w:1)
+	// Storage: Chocolate Projects (r:0 w:1)
+	fn create_project() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate RecentTransfers (r:1 w:1)
+	// Storage: Identity SuperOf (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Chocolate LatestReviews (r:1 w:1)
+	// Storage: Chocolate ReviewRounds (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Users Users (r:2 w:1)
+	// Storage: Chocolate NextReviewIndex (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Chocolate Reviews (r:0 w:1)
+	// Storage: Chocolate ProposedReviews (r:0 w:1)
+	fn create_review() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Chocolate ReviewRounds (r:1 w:0)
+	// Storage: Users Users (r:1 w:0)
+	// Storage: Chocolate Flags (r:0 w:1)
+	fn edit_review() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Users Users (r:2 w:1)
+	// Storage: Chocolate ReviewVotes (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	fn vote_review() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Chocolate Reviews (r:1 w:0)
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ReviewResponses (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn respond_to_review() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Chocolate Reviews (r:1 w:0)
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ReviewResponses (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	fn remove_review_response() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Chocolate ReviewVotes (r:1 w:1)
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	fn retract_vote() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: Chocolate ProposedReviews (r:0 w:1)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	fn accept_review() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Users Vouches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Chocolate ProposedReviews (r:0 w:1)
+	fn reject_review() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:0)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Chocolate ProjectsByStatus (r:0 w:2)
+	// Storage: Chocolate RequireIdentity (r:1 w:0)
+	fn accept_project() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate MetadataHistory (r:1 w:1)
+	// Storage: Chocolate ProjectsByStatus (r:0 w:2)
+	fn update_project_metadata() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ReviewRounds (r:1 w:1)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:0)
+	// Storage: Chocolate ProjectsByStatus (r:0 w:2)
+	// Storage: Chocolate PendingOwners (r:0 w:1)
+	// Storage: Chocolate ProjectsByCategory (r:0 w:1)
+	// Storage: Chocolate ReviewResponses (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Chocolate ProjectTags (r:1 w:1)
+	// Storage: Chocolate ProjectsByTag (r:0 w:1)
+	// Storage: Chocolate ProjectTeams (r:0 w:1)
+	// Storage: Chocolate MetadataHistory (r:0 w:1)
+	fn close_project(r: u32, t: u32, ) -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate PendingOwners (r:0 w:1)
+	fn propose_owner() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate PendingOwners (r:1 w:1)
+	// Storage: Users Users (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Chocolate ProjectTeams (r:1 w:1)
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	// Storage: Chocolate ReviewCommits (r:1 w:0)
+	// Storage: Chocolate RequireIdentity (r:1 w:0)
+	fn accept_ownership() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:1)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate Reviews (r:1 w:0)
+	// Storage: Chocolate ReviewCommits (r:1 w:0)
+	fn set_team_member() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:1)
+	fn remove_team_member() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn top_up_reward() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Chocolate Categories (r:1 w:1)
+	fn add_category() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Categories (r:1 w:1)
+	// Storage: Chocolate ProjectsByCategory (r:1 w:0)
+	fn remove_category() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate ProjectTags (r:1 w:1)
+	// Storage: Chocolate ProjectsByTag (r:0 w:2)
+	fn set_project_tags(t: u32, ) -> Weight {
+		EXECUTION
+			.saturating_add(PER_TAG.saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate ReviewRounds (r:1 w:1)
+	fn start_review_round() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate ReviewRounds (r:1 w:0)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate RecentTransfers (r:1 w:1)
+	// Storage: Identity SuperOf (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Chocolate LatestReviews (r:1 w:0)
+	// Storage: Chocolate ReviewCommits (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Users Users (r:1 w:1)
+	fn commit_review() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Chocolate ReviewCommits (r:1 w:1)
+	// Storage: Chocolate Projects (r:1 w:1)
+	// Storage: Chocolate ProjectTeams (r:1 w:0)
+	// Storage: Chocolate RecentTransfers (r:1 w:1)
+	// Storage: Identity SuperOf (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:0)
+	// Storage: Chocolate LatestReviews (r:1 w:1)
+	// Storage: Users Users (r:3 w:2)
+	// Storage: Chocolate NextReviewIndex (r:1 w:1)
+	// Storage: Chocolate Reviews (r:0 w:1)
+	// Storage: Chocolate ProposedReviews (r:0 w:1)
+	fn reveal_review() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Chocolate ReviewCommits (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	// Storage: Users Users (r:1 w:1)
+	fn forfeit_commit() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Chocolate ContentVerifiers (r:1 w:1)
+	fn add_content_verifier() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate ContentVerifiers (r:1 w:1)
+	fn remove_content_verifier() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate ContentVerifiers (r:1 w:0)
+	// Storage: Chocolate Projects (r:1 w:0)
+	// Storage: Chocolate Flags (r:1 w:1)
+	fn flag_content() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate Flags (r:1 w:1)
+	fn clear_flag() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate RecentTransfers (r:0 w:1)
+	fn note_transfer() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Chocolate RequireIdentity (r:0 w:1)
+	fn set_identity_requirement() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -1,20 +1,97 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-minting
 
 use super::*;
-
 #[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_system::RawOrigin;
+use crate::Pallet as Minting;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{
+	sp_runtime::{traits::Saturating, Perbill},
+	traits::{Currency, EnsureOrigin, Get, Hooks},
+};
+use orml_traits::MultiCurrency;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// A decaying schedule emitting every block.
+fn schedule<T: Config>() -> EmissionScheduleOf<T> {
+	EmissionSchedule {
+		amount: T::Currency::minimum_balance().saturating_mul(1_000u32.into()),
+		period: 1u32.into(),
+		decay: Some(Perbill::from_percent(1)),
+	}
+}
 
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
-		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+	mint {
+		let origin = T::ApproveOrigin::successful_origin();
+		let amount = T::Currency::minimum_balance().saturating_mul(1_000u32.into());
+	}: _<T::Origin>(origin, amount)
+	verify {
+		assert_last_event::<T>(Event::Minted(amount).into());
+	}
+
+	// worst case locks the minted tokens behind a vesting schedule
+	mint_to {
+		let origin = T::ApproveOrigin::successful_origin();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let amount = T::Currency::minimum_balance().saturating_mul(1_000u32.into());
+		let per_block = T::Currency::minimum_balance();
+		let vesting = VestingTerms { per_block, starting_block: 1u32.into() };
+	}: _<T::Origin>(origin, beneficiary.clone(), amount, Some(vesting))
+	verify {
+		assert_last_event::<T>(Event::MintedTo(beneficiary, amount).into());
+	}
+
+	mint_currency {
+		let origin = T::ApproveOrigin::successful_origin();
+		let currency_id = T::BenchmarkCurrencyId::get();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let amount = T::MultiCurrency::minimum_balance(currency_id).saturating_mul(1_000u32.into());
+	}: _<T::Origin>(origin, currency_id, beneficiary.clone(), amount)
+	verify {
+		assert_last_event::<T>(Event::CurrencyMinted(currency_id, beneficiary, amount).into());
+	}
+
+	burn_currency {
+		let origin = T::ApproveOrigin::successful_origin();
+		let currency_id = T::BenchmarkCurrencyId::get();
+		let who: T::AccountId = account("holder", 0, 0);
+		let amount = T::MultiCurrency::minimum_balance(currency_id).saturating_mul(1_000u32.into());
+		Minting::<T>::mint_currency(origin.clone(), currency_id, who.clone(), amount)?;
+	}: _<T::Origin>(origin, currency_id, who.clone(), amount)
+	verify {
+		assert_last_event::<T>(Event::CurrencyBurned(currency_id, who, amount).into());
+	}
+
+	set_emission_schedule {
+		let origin = T::ApproveOrigin::successful_origin();
+		let schedule = schedule::<T>();
+	}: _<T::Origin>(origin, schedule.clone())
+	verify {
+		assert_last_event::<T>(Event::EmissionScheduleSet(schedule).into());
+	}
+
+	clear_emission_schedule {
+		let origin = T::ApproveOrigin::successful_origin();
+		Minting::<T>::set_emission_schedule(origin.clone(), schedule::<T>())?;
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(Minting::<T>::emission().is_none());
+	}
+
+	// worst case decays the schedule and keeps it for another period
+	emit {
+		let origin = T::ApproveOrigin::successful_origin();
+		Minting::<T>::set_emission_schedule(origin, schedule::<T>())?;
+		let now = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+	}: {
+		Minting::<T>::on_initialize(now);
+	}
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert_last_event::<T>(Event::Emitted(schedule::<T>().amount).into());
 	}
 }
 
-impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test,);
+impl_benchmark_test_suite!(Minting, crate::mock::new_test_ext(), crate::mock::Test);
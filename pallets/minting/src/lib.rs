@@ -14,15 +14,23 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
 		assert_ok,
 		pallet_prelude::*,
-		sp_runtime::traits::Zero,
-		traits::{Currency, Imbalance, OnUnbalanced},
+		sp_runtime::{
+			traits::{Saturating, Zero},
+			PerThing, Perbill,
+		},
+		traits::{Currency, Imbalance, OnUnbalanced, VestingSchedule},
 	};
 	use frame_system::{pallet_prelude::*, Origin};
+	use orml_traits::{GetByKey, MultiCurrency};
+	use sp_std::vec::Vec;
 
 	use super::*;
 
@@ -36,68 +44,295 @@ pub mod pallet {
 		type TreasuryOutlet: OnUnbalanced<NegativeImbalanceOf<Self>>;
 		///  Origins that must approve to use the pallet - Should be implemented properly by provider.
 		type ApproveOrigin: EnsureOrigin<Self::Origin>;
+		/// The most the total issuance may reach through minting. Scheduled emissions are cut
+		/// short at the cap, and mints going over it fail.
+		#[pallet::constant]
+		type MaxIssuance: Get<BalanceOf<Self>>;
+		/// Vesting schedules, to lock tokens minted to beneficiaries.
+		type Vesting: VestingSchedule<
+			Self::AccountId,
+			Moment = Self::BlockNumber,
+			Currency = Self::Currency,
+		>;
+		/// The currencies other than the native one that governance can mint and burn, e.g. for
+		/// collateral on test deployments.
+		type MultiCurrency: MultiCurrency<Self::AccountId>;
+		/// The most the total issuance of each currency may reach through `mint_currency`.
+		type SupplyCaps: GetByKey<CurrencyIdOf<Self>, MultiBalanceOf<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// A currency with room under its supply cap, for benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkCurrencyId: Get<CurrencyIdOf<Self>>;
 	}
 
-	type BalanceOf<T> =
+	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 		<T as frame_system::Config>::AccountId,
 	>>::NegativeImbalance;
 
+	pub type CurrencyIdOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
+		<T as frame_system::Config>::AccountId,
+	>>::CurrencyId;
+
+	pub type MultiBalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	pub type EmissionScheduleOf<T> =
+		EmissionSchedule<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+	/// An emission schedule set by governance: mints `amount` to the treasury every `period`
+	/// blocks, e.g. 1 for every block or the length of an era.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct EmissionSchedule<Balance, BlockNumber> {
+		/// Amount minted by the next emission.
+		pub amount: Balance,
+		/// Blocks between emissions.
+		pub period: BlockNumber,
+		/// Share of `amount` dropped after each emission, rounded up so the emissions reach zero.
+		pub decay: Option<Perbill>,
+	}
+
+	pub type VestingTermsOf<T> =
+		VestingTerms<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+	/// Linear vesting of tokens minted to a beneficiary: the whole amount is locked, and
+	/// `per_block` of it unlocks every block from `starting_block`.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct VestingTerms<Balance, BlockNumber> {
+		/// Amount unlocked per block.
+		pub per_block: Balance,
+		/// Block from which the tokens start to unlock.
+		pub starting_block: BlockNumber,
+	}
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	pub struct Pallet<T>(_);
 
+	/// The emission schedule and the block of its next emission.
+	#[pallet::storage]
+	#[pallet::getter(fn emission)]
+	pub type Emission<T: Config> = StorageValue<_, (EmissionScheduleOf<T>, T::BlockNumber)>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Parameters. [Amount]
 		Minted(BalanceOf<T>),
+		/// Parameters. [Beneficiary, Amount]
+		MintedTo(T::AccountId, BalanceOf<T>),
+		/// Parameters. [Schedule]
+		EmissionScheduleSet(EmissionScheduleOf<T>),
+		/// Governance stopped the scheduled emissions.
+		EmissionScheduleCleared,
+		/// Parameters. [Amount]
+		Emitted(BalanceOf<T>),
+		/// The emissions decayed to zero.
+		EmissionScheduleEnded,
+		/// Parameters. [Currency, Beneficiary, Amount]
+		CurrencyMinted(CurrencyIdOf<T>, T::AccountId, MultiBalanceOf<T>),
+		/// Parameters. [Currency, Account, Amount]
+		CurrencyBurned(CurrencyIdOf<T>, T::AccountId, MultiBalanceOf<T>),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Mint Must be called from a root or equivalent origin
 		InvalidOrigin,
+		/// Minting would take the total issuance over `MaxIssuance`
+		IssuanceCapExceeded,
+		/// Emission periods must be at least one block
+		InvalidEmissionPeriod,
+		/// Emission schedules must mint something
+		ZeroEmission,
+		/// There is no emission schedule
+		NoEmissionSchedule,
+		/// Minting would take the total issuance of the currency over its supply cap
+		SupplyCapExceeded,
+		/// The amount is too small to create the beneficiary's account
+		BelowExistentialDeposit,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			match <Emission<T>>::get() {
+				Some((schedule, next)) if next <= now => {
+					Pallet::<T>::emit(schedule, now);
+					T::WeightInfo::emit()
+				},
+				_ => T::DbWeight::get().reads(1),
+			}
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::mint())]
 		pub fn mint(origin: OriginFor<T>, x: BalanceOf<T>) -> DispatchResult {
 			// call its ensure origin - doesn't return origin. Only checks
 			T::ApproveOrigin::ensure_origin(origin)?;
+			ensure!(x <= Pallet::<T>::headroom(), Error::<T>::IssuanceCapExceeded);
 			let imbalance = T::Currency::issue(x);
 			let minted = imbalance.peek();
 			Self::do_mint(imbalance);
 			Self::deposit_event(Event::Minted(minted));
 			Ok(())
 		}
+
+		/// Mint directly to an account, e.g. for token sales and grants. With vesting terms,
+		/// the minted tokens are locked and unlock linearly. Fails rather than minting nothing
+		/// when a new beneficiary would get less than the existential deposit.
+		///
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::mint_to())]
+		pub fn mint_to(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+			vesting: Option<VestingTermsOf<T>>,
+		) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			// CHECKS
+			ensure!(amount <= Pallet::<T>::headroom(), Error::<T>::IssuanceCapExceeded);
+			if let Some(terms) = &vesting {
+				let (per_block, start) = (terms.per_block, terms.starting_block);
+				T::Vesting::can_add_vesting_schedule(&beneficiary, amount, per_block, start)?;
+			}
+			// MUTATIONS
+			let minted = T::Currency::deposit_creating(&beneficiary, amount).peek();
+			ensure!(minted == amount, Error::<T>::BelowExistentialDeposit);
+			if let Some(terms) = vesting {
+				let (per_block, start) = (terms.per_block, terms.starting_block);
+				T::Vesting::add_vesting_schedule(&beneficiary, minted, per_block, start)?;
+			}
+			Self::deposit_event(Event::MintedTo(beneficiary, minted));
+			Ok(())
+		}
+
+		/// Set the emission schedule, replacing any current one. The first emission is one
+		/// period from now.
+		///
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::set_emission_schedule())]
+		pub fn set_emission_schedule(
+			origin: OriginFor<T>,
+			schedule: EmissionScheduleOf<T>,
+		) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			// CHECKS
+			ensure!(!schedule.period.is_zero(), Error::<T>::InvalidEmissionPeriod);
+			ensure!(!schedule.amount.is_zero(), Error::<T>::ZeroEmission);
+			// STORAGE MUTATIONS
+			let next = <frame_system::Pallet<T>>::block_number().saturating_add(schedule.period);
+			<Emission<T>>::put((schedule.clone(), next));
+			Self::deposit_event(Event::EmissionScheduleSet(schedule));
+			Ok(())
+		}
+
+		/// Mint any currency to an account, up to the currency's supply cap.
+		///
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::mint_currency())]
+		pub fn mint_currency(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			beneficiary: T::AccountId,
+			amount: MultiBalanceOf<T>,
+		) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			// CHECKS
+			let issuance = T::MultiCurrency::total_issuance(currency_id);
+			let cap = T::SupplyCaps::get(&currency_id);
+			ensure!(amount <= cap.saturating_sub(issuance), Error::<T>::SupplyCapExceeded);
+			// MUTATIONS
+			T::MultiCurrency::deposit(currency_id, &beneficiary, amount)?;
+			Self::deposit_event(Event::CurrencyMinted(currency_id, beneficiary, amount));
+			Ok(())
+		}
+
+		/// Burn any currency from an account.
+		///
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::burn_currency())]
+		pub fn burn_currency(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			who: T::AccountId,
+			amount: MultiBalanceOf<T>,
+		) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			// MUTATIONS
+			T::MultiCurrency::withdraw(currency_id, &who, amount)?;
+			Self::deposit_event(Event::CurrencyBurned(currency_id, who, amount));
+			Ok(())
+		}
+
+		/// Stop the scheduled emissions.
+		///
+		/// Must be called by Root-like (Council or CES).
+		#[pallet::weight(T::WeightInfo::clear_emission_schedule())]
+		pub fn clear_emission_schedule(origin: OriginFor<T>) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			ensure!(<Emission<T>>::exists(), Error::<T>::NoEmissionSchedule);
+			<Emission<T>>::kill();
+			Self::deposit_event(Event::EmissionScheduleCleared);
+			Ok(())
+		}
 	}
 	impl<T: Config> Pallet<T> {
 		/// Function to take negative imbalance to the treasury, expected to be called after creating one e.g through T::Currency::issue()
 		pub fn do_mint(amount: NegativeImbalanceOf<T>) {
 			T::TreasuryOutlet::on_unbalanced(amount);
 		}
+
+		/// How much can still be minted before the total issuance reaches `MaxIssuance`.
+		pub fn headroom() -> BalanceOf<T> {
+			T::MaxIssuance::get().saturating_sub(T::Currency::total_issuance())
+		}
+
+		/// Mint the scheduled amount to the treasury, as far as the issuance cap allows, then
+		/// decay the schedule and move it to its next period. Ends the schedule once it has
+		/// decayed to zero.
+		pub fn emit(mut schedule: EmissionScheduleOf<T>, now: T::BlockNumber) {
+			let amount = schedule.amount.min(Pallet::<T>::headroom());
+			if !amount.is_zero() {
+				Self::do_mint(T::Currency::issue(amount));
+				Self::deposit_event(Event::Emitted(amount));
+			}
+			if let Some(decay) = schedule.decay {
+				schedule.amount = schedule.amount.saturating_sub(decay.mul_ceil(schedule.amount));
+			}
+			if schedule.amount.is_zero() {
+				<Emission<T>>::kill();
+				Self::deposit_event(Event::EmissionScheduleEnded);
+			} else {
+				let next = now.saturating_add(schedule.period);
+				<Emission<T>>::put((schedule, next));
+			}
+		}
 	}
 
-	/// Genesis config for the minting pallet. Use to mint an initial amount to treasury
-	/// E.g Use: Token sales.
+	/// Genesis config for the minting pallet. Use to mint an initial amount to treasury, and
+	/// to beneficiaries. E.g Use: Token sales and grants.
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// Amount to mint
 		pub init_mint: BalanceOf<T>,
+		/// Beneficiaries, the amount minted to them and optionally its vesting, as
+		/// `(per_block, starting_block)`.
+		pub init_mint_to: Vec<(T::AccountId, BalanceOf<T>, Option<(BalanceOf<T>, T::BlockNumber)>)>,
 	}
 
 	/// By default, nothing.
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			Self { init_mint: Zero::zero() }
+			Self { init_mint: Zero::zero(), init_mint_to: Vec::new() }
 		}
 	}
 
@@ -107,6 +342,13 @@ pub mod pallet {
 			// Repeat mint call, without origin check
 			let dispatch = <Pallet<T>>::mint(Origin::<T>::Root.into(), self.init_mint);
 			assert_ok!(dispatch);
+			for (beneficiary, amount, vesting) in self.init_mint_to.iter().cloned() {
+				let vesting = vesting
+					.map(|(per_block, starting_block)| VestingTerms { per_block, starting_block });
+				let dispatch =
+					<Pallet<T>>::mint_to(Origin::<T>::Root.into(), beneficiary, amount, vesting);
+				assert_ok!(dispatch);
+			}
 		}
 	}
 }
@@ -1,10 +1,14 @@
 use crate as pallet_minting;
-use frame_support::{parameter_types, traits::Everything};
+use frame_support::{
+	parameter_types,
+	traits::{Currency, Everything, Hooks, Nothing, OnUnbalanced},
+};
 use frame_system as system;
+use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -18,6 +22,9 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>, Config<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		MintingModule: pallet_minting::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -25,6 +32,12 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 42;
+	pub const MaxIssuance: u64 = MAX_ISSUANCE;
+	pub const MinVestedTransfer: u64 = 10;
+	pub const BenchmarkCurrencyId: u32 = DOT;
+}
+parameter_types! {
+	pub static ExistentialDeposit: u64 = 1;
 }
 
 impl system::Config for Test {
@@ -45,7 +58,7 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -54,14 +67,105 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+impl pallet_vesting::Config for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = MinVestedTransfer;
+	type WeightInfo = ();
+	const MAX_VESTING_SCHEDULES: u32 = 3;
+}
+
+/// A currency minted up to `DOT_SUPPLY_CAP`.
+pub const DOT: u32 = 1;
+/// A currency without a supply cap, so it cannot be minted.
+pub const BTC: u32 = 2;
+/// The most the total issuance of `DOT` may reach.
+pub const DOT_SUPPLY_CAP: u64 = 1_000;
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: u32| -> u64 {
+		1
+	};
+}
+
+parameter_type_with_key! {
+	pub SupplyCaps: |currency_id: u32| -> u64 {
+		match *currency_id {
+			DOT => DOT_SUPPLY_CAP,
+			_ => 0,
+		}
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = u64;
+	type Amount = i64;
+	type CurrencyId = u32;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type DustRemovalWhitelist = Nothing;
+}
+
+/// The most the total issuance may reach through minting.
+pub const MAX_ISSUANCE: u64 = 10_000;
+
+/// Account the minted funds are moved to, standing in for the treasury.
+pub const TREASURY: u64 = 100;
+
+/// Moves minted funds to `TREASURY`.
+pub struct ToTreasury;
+impl OnUnbalanced<pallet_minting::NegativeImbalanceOf<Test>> for ToTreasury {
+	fn on_nonzero_unbalanced(amount: pallet_minting::NegativeImbalanceOf<Test>) {
+		Balances::resolve_creating(&TREASURY, amount);
+	}
+}
+
 impl pallet_minting::Config for Test {
 	type Event = Event;
 	type Currency = Balances;
-	type TreasuryOutlet = Treasury;
-	type ApproveOrigin = ApproveOrigin;
+	type TreasuryOutlet = ToTreasury;
+	type ApproveOrigin = frame_system::EnsureRoot<u64>;
+	type MaxIssuance = MaxIssuance;
+	type Vesting = Vesting;
+	type MultiCurrency = Tokens;
+	type SupplyCaps = SupplyCaps;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkCurrencyId = BenchmarkCurrencyId;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut ext: sp_io::TestExternalities =
+		system::GenesisConfig::default().build_storage::<Test>().unwrap().into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Advance to block `n`, running the minting hook on each block.
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		MintingModule::on_initialize(System::block_number());
+	}
 }
@@ -1,16 +1,210 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, Emission, EmissionSchedule, Error, Event as MintingEvent, VestingTerms};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, VestingSchedule},
+};
+use orml_traits::MultiCurrency;
+use sp_runtime::{DispatchError, Perbill};
 
 #[test]
-fn it_works_for_default_value() {
+fn mint_should_work() {
 	new_test_ext().execute_with(|| {
-		// Dispatch a signed extrinsic.
+		assert_ok!(MintingModule::mint(Origin::root(), 1_000));
+		assert_eq!(Balances::free_balance(TREASURY), 1_000);
+		assert_eq!(Balances::total_issuance(), 1_000);
+		System::assert_last_event(MintingEvent::Minted(1_000).into());
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn mint_should_fail() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
+		assert_noop!(MintingModule::mint(Origin::signed(1), 1_000), DispatchError::BadOrigin);
+		assert_noop!(
+			MintingModule::mint(Origin::root(), MAX_ISSUANCE + 1),
+			Error::<Test>::IssuanceCapExceeded
+		);
+	});
+}
+
+#[test]
+fn mint_to_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MintingModule::mint_to(Origin::root(), 1, 1_000, None));
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(Balances::total_issuance(), 1_000);
+		System::assert_last_event(MintingEvent::MintedTo(1, 1_000).into());
+		// 100 unlocks every block from block 2
+		let vesting = VestingTerms { per_block: 100, starting_block: 2 };
+		assert_ok!(MintingModule::mint_to(Origin::root(), 2, 1_000, Some(vesting)));
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_eq!(Vesting::vesting_balance(&2), Some(1_000));
+		assert_noop!(
+			Balances::transfer(Origin::signed(2), 3, 100),
+			pallet_balances::Error::<Test>::LiquidityRestrictions
+		);
+		run_to_block(4);
+		assert_eq!(Vesting::vesting_balance(&2), Some(800));
+		assert_ok!(Vesting::vest(Origin::signed(2)));
+		assert_ok!(Balances::transfer(Origin::signed(2), 3, 200));
+	});
+}
+
+#[test]
+fn mint_to_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MintingModule::mint_to(Origin::signed(1), 1, 1_000, None),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			MintingModule::mint_to(Origin::root(), 1, MAX_ISSUANCE + 1, None),
+			Error::<Test>::IssuanceCapExceeded
+		);
+		let vesting = VestingTerms { per_block: 0, starting_block: 2 };
+		assert_noop!(
+			MintingModule::mint_to(Origin::root(), 1, 1_000, Some(vesting)),
+			pallet_vesting::Error::<Test>::InvalidScheduleParams
+		);
+		ExistentialDeposit::set(10);
+		assert_noop!(
+			MintingModule::mint_to(Origin::root(), 99, 9, None),
+			Error::<Test>::BelowExistentialDeposit
+		);
+		// Existing accounts can receive less than the existential deposit.
+		assert_ok!(MintingModule::mint_to(Origin::root(), 1, 10, None));
+		assert_ok!(MintingModule::mint_to(Origin::root(), 1, 9, None));
+		assert_eq!(Balances::free_balance(1), 19);
+	});
+}
+
+#[test]
+fn mint_currency_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MintingModule::mint_currency(Origin::root(), DOT, 1, 600));
+		assert_eq!(Tokens::free_balance(DOT, &1), 600);
+		assert_eq!(Tokens::total_issuance(DOT), 600);
+		System::assert_last_event(MintingEvent::CurrencyMinted(DOT, 1, 600).into());
+		assert_ok!(MintingModule::burn_currency(Origin::root(), DOT, 1, 200));
+		assert_eq!(Tokens::free_balance(DOT, &1), 400);
+		System::assert_last_event(MintingEvent::CurrencyBurned(DOT, 1, 200).into());
+		// burning frees room under the cap
+		assert_ok!(MintingModule::mint_currency(Origin::root(), DOT, 2, 600));
+		assert_eq!(Tokens::total_issuance(DOT), DOT_SUPPLY_CAP);
+		// the native currency is untouched
+		assert_eq!(Balances::total_issuance(), 0);
+	});
+}
+
+#[test]
+fn mint_currency_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MintingModule::mint_currency(Origin::signed(1), DOT, 1, 600),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			MintingModule::mint_currency(Origin::root(), DOT, 1, DOT_SUPPLY_CAP + 1),
+			Error::<Test>::SupplyCapExceeded
+		);
+		assert_noop!(
+			MintingModule::mint_currency(Origin::root(), BTC, 1, 1),
+			Error::<Test>::SupplyCapExceeded
+		);
+		assert_noop!(
+			MintingModule::burn_currency(Origin::signed(1), DOT, 1, 100),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			MintingModule::burn_currency(Origin::root(), DOT, 1, 100),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn emission_schedule_should_work() {
+	new_test_ext().execute_with(|| {
+		let schedule = EmissionSchedule { amount: 100, period: 2, decay: None };
+		assert_ok!(MintingModule::set_emission_schedule(Origin::root(), schedule.clone()));
+		System::assert_last_event(MintingEvent::EmissionScheduleSet(schedule.clone()).into());
+		assert_eq!(Emission::<Test>::get(), Some((schedule.clone(), 3)));
+		run_to_block(2);
+		assert_eq!(Balances::total_issuance(), 0);
+		run_to_block(3);
+		assert_eq!(Balances::free_balance(TREASURY), 100);
+		System::assert_last_event(MintingEvent::Emitted(100).into());
+		run_to_block(7);
+		assert_eq!(Balances::free_balance(TREASURY), 300);
+		assert_eq!(Emission::<Test>::get(), Some((schedule, 9)));
+		assert_ok!(MintingModule::clear_emission_schedule(Origin::root()));
+		System::assert_last_event(MintingEvent::EmissionScheduleCleared.into());
+		run_to_block(9);
+		assert_eq!(Balances::free_balance(TREASURY), 300);
+	});
+}
+
+#[test]
+fn emission_schedule_should_decay() {
+	new_test_ext().execute_with(|| {
+		let decay = Some(Perbill::from_percent(50));
+		let schedule = EmissionSchedule { amount: 100, period: 1, decay };
+		assert_ok!(MintingModule::set_emission_schedule(Origin::root(), schedule));
+		run_to_block(3);
+		assert_eq!(Balances::free_balance(TREASURY), 150);
+		assert_eq!(
+			Emission::<Test>::get(),
+			Some((EmissionSchedule { amount: 25, period: 1, decay }, 4))
+		);
+		// 25, 12, 6, 3 and 1 remain, as decay rounds up
+		run_to_block(8);
+		assert_eq!(Balances::free_balance(TREASURY), 197);
+		assert_eq!(Emission::<Test>::get(), None);
+		System::assert_last_event(MintingEvent::EmissionScheduleEnded.into());
+	});
+}
+
+#[test]
+fn emission_schedule_should_respect_cap() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MintingModule::mint(Origin::root(), MAX_ISSUANCE - 50));
+		let schedule = EmissionSchedule { amount: 100, period: 1, decay: None };
+		assert_ok!(MintingModule::set_emission_schedule(Origin::root(), schedule.clone()));
+		run_to_block(2);
+		assert_eq!(Balances::total_issuance(), MAX_ISSUANCE);
+		System::assert_last_event(MintingEvent::Emitted(50).into());
+		// At the cap nothing is emitted, but the schedule carries on.
+		run_to_block(3);
+		assert_eq!(Balances::total_issuance(), MAX_ISSUANCE);
+		assert_eq!(Emission::<Test>::get(), Some((schedule, 4)));
+	});
+}
+
+#[test]
+fn emission_schedule_should_fail() {
+	new_test_ext().execute_with(|| {
+		let schedule = EmissionSchedule { amount: 100, period: 1, decay: None };
+		assert_noop!(
+			MintingModule::set_emission_schedule(Origin::signed(1), schedule.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			MintingModule::set_emission_schedule(
+				Origin::root(),
+				EmissionSchedule { period: 0, ..schedule.clone() }
+			),
+			Error::<Test>::InvalidEmissionPeriod
+		);
+		assert_noop!(
+			MintingModule::set_emission_schedule(
+				Origin::root(),
+				EmissionSchedule { amount: 0, ..schedule }
+			),
+			Error::<Test>::ZeroEmission
+		);
+		assert_noop!(
+			MintingModule::clear_emission_schedule(Origin::root()),
+			Error::<Test>::NoEmissionSchedule
+		);
 	});
 }
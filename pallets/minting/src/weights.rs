@@ -0,0 +1,137 @@
+//! Weights for pallet_minting
+//!
+//! Placeholders priced from storage alone: each call costs its worst-case reads and writes at
+//! `DbWeight`, plus the flat `EXECUTION` allowance. Overwrite with benchmarked weights,
+//! generated on reference hardware from the repository root with:
+//!
+//! ./scripts/benchmark.sh minting
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Flat allowance for the computation of a call, on top of its storage accesses.
+pub const EXECUTION: Weight = 50_000_000;
+
+/// Weight functions needed for pallet_minting.
+pub trait WeightInfo {
+	fn mint() -> Weight;
+	fn mint_to() -> Weight;
+	fn mint_currency() -> Weight;
+	fn burn_currency() -> Weight;
+	fn set_emission_schedule() -> Weight;
+	fn clear_emission_schedule() -> Weight;
+	fn emit() -> Weight;
+}
+
+/// Weights for pallet_minting, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn mint() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn mint_to() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn mint_currency() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn burn_currency() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Minting Emission (r:0 w:1)
+	fn set_emission_schedule() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Minting Emission (r:1 w:1)
+	fn clear_emission_schedule() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Minting Emission (r:1 w:1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn emit() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn mint() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn mint_to() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn mint_currency() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn burn_currency() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Minting Emission (r:0 w:1)
+	fn set_emission_schedule() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Minting Emission (r:1 w:1)
+	fn clear_emission_schedule() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Minting Emission (r:1 w:1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn emit() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
@@ -0,0 +1,151 @@
+//! Benchmarking setup for pallet-users
+
+use super::*;
+#[allow(unused)]
+use crate::Pallet as Users;
+use chocolate_primitives::users::{UserProfile, MAX_DISPLAY_NAME_LENGTH, MAX_USERNAME_LENGTH};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	sp_runtime::traits::Bounded,
+	traits::{Currency, Get},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_core::ecdsa;
+use sp_io::hashing::keccak_256;
+use sp_std::vec;
+
+const CID: &[u8] = b"QmPAVb28J73KjzcjTQ85oJLyftmm4kEgTTptRssPnKjKZD";
+
+fn bounded<T: Config>(bytes: &[u8]) -> BoundedVec<u8, T::StringLimit> {
+	bytes.to_vec().try_into().expect("Should be within string limit")
+}
+
+/// A profile with every field at its longest, under a username of `fill` characters.
+fn profile<T: Config>(fill: u8) -> UserProfileOf<T> {
+	UserProfile {
+		username: bounded::<T>(&vec![fill; MAX_USERNAME_LENGTH]),
+		display_name: bounded::<T>(&vec![b'a'; MAX_DISPLAY_NAME_LENGTH]),
+		avatar: Some(bounded::<T>(CID)),
+		bio: Some(bounded::<T>(CID)),
+	}
+}
+
+/// A registered user with enough balance for any deposit.
+fn funded_user<T: Config>() -> T::AccountId {
+	registered::<T>(whitelisted_caller())
+}
+
+fn registered<T: Config>(who: T::AccountId) -> T::AccountId {
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	Users::<T>::make_user(RawOrigin::Signed(who.clone()).into())
+		.expect("Account should not be registered yet");
+	who
+}
+
+/// A registered user with enough rank points to vouch.
+fn established_user<T: Config>(name: &'static str) -> T::AccountId {
+	let who = registered::<T>(account(name, 0, 0));
+	<crate::Users<T>>::mutate(&who, |user| {
+		user.as_mut().expect("User was just registered").rank_points = T::MinVoucherRank::get()
+	});
+	who
+}
+
+/// An `eth_sign` signature linking `who` to the Ethereum account of `secret`.
+fn eth_signature<T: Config>(
+	who: &T::AccountId,
+	secret: &libsecp256k1::SecretKey,
+) -> ecdsa::Signature {
+	let message = who.using_encoded(to_ascii_hex);
+	let hash = keccak_256(&Users::<T>::eth_signable_message(&message));
+	let (signature, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&hash), secret);
+	let mut raw = [0u8; 65];
+	raw[..64].copy_from_slice(&signature.serialize());
+	raw[64] = recovery_id.serialize();
+	ecdsa::Signature::from_raw(raw)
+}
+
+fn eth_secret() -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&keccak_256(b"benchmark")).expect("Hash is a valid secret key")
+}
+
+benchmarks! {
+	make_user {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Users::<T>::users(&caller).is_some());
+	}
+
+	// worst case also clears a profile, an Ethereum link and releases a voucher's bond
+	deregister {
+		let caller = funded_user::<T>();
+		Users::<T>::set_profile(RawOrigin::Signed(caller.clone()).into(), profile::<T>(b'a'))?;
+		let signature = eth_signature::<T>(&caller, &eth_secret());
+		Users::<T>::link_eth_address(RawOrigin::Signed(caller.clone()).into(), signature)?;
+		let voucher = established_user::<T>("voucher");
+		Users::<T>::vouch(RawOrigin::Signed(voucher).into(), caller.clone())?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Users::<T>::users(&caller).is_none());
+		assert!(Users::<T>::usernames(profile::<T>(b'a').username).is_none());
+		assert!(Users::<T>::vouches(&caller).is_none());
+		assert!(Users::<T>::eth_addresses(&caller).is_none());
+	}
+
+	vouch {
+		let voucher = established_user::<T>("voucher");
+		let vouchee = funded_user::<T>();
+	}: _(RawOrigin::Signed(voucher.clone()), vouchee.clone())
+	verify {
+		assert_eq!(Users::<T>::vouches(&vouchee), Some((voucher, T::VouchBond::get())));
+	}
+
+	unvouch {
+		let voucher = established_user::<T>("voucher");
+		let vouchee = funded_user::<T>();
+		Users::<T>::vouch(RawOrigin::Signed(voucher.clone()).into(), vouchee.clone())?;
+	}: _(RawOrigin::Signed(voucher), vouchee.clone())
+	verify {
+		assert!(Users::<T>::vouches(&vouchee).is_none());
+	}
+
+	// worst case replaces a profile under another username, so both registry entries change
+	set_profile {
+		let caller = funded_user::<T>();
+		Users::<T>::set_profile(RawOrigin::Signed(caller.clone()).into(), profile::<T>(b'a'))?;
+	}: _(RawOrigin::Signed(caller.clone()), profile::<T>(b'b'))
+	verify {
+		assert!(Users::<T>::usernames(profile::<T>(b'a').username).is_none());
+		assert_eq!(Users::<T>::usernames(profile::<T>(b'b').username), Some(caller));
+	}
+
+	clear_profile {
+		let caller = funded_user::<T>();
+		Users::<T>::set_profile(RawOrigin::Signed(caller.clone()).into(), profile::<T>(b'a'))?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Users::<T>::profiles(&caller).is_none());
+	}
+
+	link_eth_address {
+		let caller = funded_user::<T>();
+		let signature = eth_signature::<T>(&caller, &eth_secret());
+	}: _(RawOrigin::Signed(caller.clone()), signature)
+	verify {
+		assert!(Users::<T>::eth_addresses(&caller).is_some());
+	}
+
+	unlink_eth_address {
+		let caller = funded_user::<T>();
+		let signature = eth_signature::<T>(&caller, &eth_secret());
+		Users::<T>::link_eth_address(RawOrigin::Signed(caller.clone()).into(), signature)?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Users::<T>::eth_addresses(&caller).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Users, crate::mock::new_test_ext(), crate::mock::Test);
@@ -9,28 +9,82 @@ pub use pallet::*;
 // this uses vec from prelude
 // use sp_std::vec::Vec;
 // this isn't accessible in the child modl pallet.
-// #[cfg(test)]
-// mod mock;
+#[cfg(test)]
+mod mock;
 
-// #[cfg(test)]
-// mod tests;
+#[cfg(test)]
+mod tests;
 
-// #[cfg(feature = "runtime-benchmarks")]
-// mod benchmarking;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use chocolate_primitives::users::*;
-	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		sp_runtime::Perbill,
+		traits::{Currency, ReservableCurrency},
+	};
 	use frame_system::pallet_prelude::*;
+	use sp_core::{ecdsa, H160};
+	use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+	use sp_std::vec::Vec;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type UserProfileOf<T> = UserProfile<<T as Config>::StringLimit>;
+	pub type UsernameOf<T> = BoundedVec<u8, <T as Config>::StringLimit>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// The currency profile deposits are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The bound on every byte string of a profile.
+		type StringLimit: Get<u32>;
+		/// Deposit reserved when a user is created, explicitly or by another pallet, and
+		/// refunded when they deregister. Makes farming accounts costly.
+		#[pallet::constant]
+		type RegistrationDeposit: Get<BalanceOf<Self>>;
+		/// Deposit reserved per byte of an encoded profile, released when it is cleared.
+		#[pallet::constant]
+		type DataDepositPerByte: Get<BalanceOf<Self>>;
+		/// Stake a voucher bonds for each user they vouch for.
+		#[pallet::constant]
+		type VouchBond: Get<BalanceOf<Self>>;
+		/// Rank points a user needs before they can vouch for others.
+		#[pallet::constant]
+		type MinVoucherRank: Get<u32>;
+		/// Rank points a user gains when vouched for.
+		#[pallet::constant]
+		type VouchRankBoost: Get<u32>;
+		/// Rank points a user loses when penalised for a malicious contribution.
+		#[pallet::constant]
+		type PenaltyRankPoints: Get<u32>;
+		/// Part of the voucher's bond burned when the user they vouched for is penalised.
+		#[pallet::constant]
+		type VouchSlash: Get<Perbill>;
+		/// Prefix of the message an Ethereum account signs to link itself to a user, followed
+		/// by the hex of the SCALE-encoded account id.
+		#[pallet::constant]
+		type LinkPrefix: Get<&'static [u8]>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::event]
@@ -38,6 +92,25 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		SomethingStored(u32, T::AccountId),
 		UserCreated(T::AccountId),
+		/// A user deregistered and got their deposit back. Parameters [who]
+		UserDeregistered(T::AccountId),
+		/// A user bonded stake to vouch for another. Parameters [voucher, vouchee]
+		Vouched(T::AccountId, T::AccountId),
+		/// A voucher withdrew their vouch and bond. Parameters [voucher, vouchee]
+		Unvouched(T::AccountId, T::AccountId),
+		/// A user was penalised for a malicious contribution. Parameters [who]
+		Penalised(T::AccountId),
+		/// A user linked an Ethereum address. Parameters [who, address]
+		EthAddressLinked(T::AccountId, H160),
+		/// A user unlinked their Ethereum address. Parameters [who, address]
+		EthAddressUnlinked(T::AccountId, H160),
+		/// Part of a voucher's bond was burned for the user they vouched for.
+		/// Parameters [voucher, vouchee, amount]
+		VoucherSlashed(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// A user set their profile. Parameters [who, username]
+		ProfileSet(T::AccountId, UsernameOf<T>),
+		/// A user cleared their profile, freeing the username. Parameters [who, username]
+		ProfileCleared(T::AccountId, UsernameOf<T>),
 	}
 
 	#[pallet::storage]
@@ -45,6 +118,39 @@ pub mod pallet {
 	/// users store
 	pub type Users<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, User>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn registration_deposits)]
+	/// The deposit reserved by each user on registration
+	pub type RegistrationDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn vouches)]
+	/// Who vouched for each user, with the bond they still have at stake
+	pub type Vouches<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::AccountId, BalanceOf<T>)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn eth_addresses)]
+	/// The Ethereum address each user proved they control
+	pub type EthAddresses<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, H160>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn eth_owners)]
+	/// The user each linked Ethereum address belongs to
+	pub type EthOwners<T: Config> = StorageMap<_, Blake2_128Concat, H160, T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn profiles)]
+	/// The profile of each user along with the deposit reserved for it
+	pub type Profiles<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (UserProfileOf<T>, BalanceOf<T>)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn usernames)]
+	/// Registry of the normalised usernames in use and who holds them
+	pub type Usernames<T: Config> = StorageMap<_, Blake2_128Concat, UsernameOf<T>, T::AccountId>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// No Value
@@ -53,24 +159,281 @@ pub mod pallet {
 		StorageOverflow,
 		/// User already exists
 		UserAlreadyExists,
+		/// The user has not been created yet
+		UserNotFound,
+		/// The username is too short, too long or has characters outside `a-z`, `0-9` and `_`
+		InvalidUsername,
+		/// The display name is empty, too long, not utf-8 or contains control characters
+		InvalidDisplayName,
+		/// The avatar is not a valid CID
+		InvalidAvatar,
+		/// The bio is not a valid CID
+		InvalidBio,
+		/// The username is held by another user
+		UsernameTaken,
+		/// The user has no profile
+		NoProfile,
+		/// The user still owns a project
+		OwnsProject,
+		/// The user still has reviews awaiting judgement
+		OpenReviews,
+		/// The voucher has fewer rank points than `MinVoucherRank`
+		InsufficientRank,
+		/// Users can't vouch for themselves
+		SelfVouch,
+		/// Someone already vouched for the user
+		AlreadyVouched,
+		/// Nobody vouched for the user
+		NotVouched,
+		/// The caller did not vouch for the user
+		NotVoucher,
+		/// The signature does not recover to an Ethereum address
+		InvalidEthSignature,
+		/// The user already linked an Ethereum address
+		EthAddressAlreadySet,
+		/// The Ethereum address is linked to another user
+		EthAddressTaken,
+		/// The user has no linked Ethereum address
+		NoEthAddress,
+	}
+
+	impl<T> From<UserProfileError> for Error<T> {
+		fn from(error: UserProfileError) -> Self {
+			match error {
+				UserProfileError::InvalidUsername => Error::<T>::InvalidUsername,
+				UserProfileError::InvalidDisplayName => Error::<T>::InvalidDisplayName,
+				UserProfileError::InvalidAvatar => Error::<T>::InvalidAvatar,
+				UserProfileError::InvalidBio => Error::<T>::InvalidBio,
+			}
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		// use base weight then add on any additional operations
-		/// Signed transaction to create user
-		#[pallet::weight(0 + T::DbWeight::get().writes(1))]
+		/// Signed transaction to create user. Reserves the `RegistrationDeposit`.
+		#[pallet::weight(T::WeightInfo::make_user())]
 		pub fn make_user(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			ensure!(!Users::<T>::contains_key(&who), Error::<T>::UserAlreadyExists);
-			<Users<T>>::insert(&who, User { rank_points: 1, project_id: Option::None });
+			Pallet::<T>::register(&who, User::default())
+		}
+
+		/// Delete the caller's user, any profile and Ethereum link, refunding their deposits and
+		/// releasing the bond of whoever vouched for them.
+		///
+		/// **Call requirements**:
+		/// - Origin must not own a project
+		/// - Origin must have no reviews awaiting judgement, nor unrevealed review commits
+		#[pallet::weight(T::WeightInfo::deregister())]
+		pub fn deregister(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let user = <Users<T>>::get(&who).ok_or(Error::<T>::UserNotFound)?;
+			let deposit = <RegistrationDeposits<T>>::get(&who);
+			// CHECKS
+			ensure!(user.project_id.is_none(), Error::<T>::OwnsProject);
+			ensure!(user.open_reviews == 0, Error::<T>::OpenReviews);
+			// MUTATIONS
+			T::Currency::unreserve(&who, deposit);
+			if let Some((profile, profile_deposit)) = <Profiles<T>>::take(&who) {
+				T::Currency::unreserve(&who, profile_deposit);
+				<Usernames<T>>::remove(&profile.username);
+			}
+			if let Some((voucher, bond)) = <Vouches<T>>::take(&who) {
+				T::Currency::unreserve(&voucher, bond);
+			}
+			if let Some(address) = <EthAddresses<T>>::take(&who) {
+				<EthOwners<T>>::remove(address);
+			}
+			// STORAGE MUTATIONS
+			<RegistrationDeposits<T>>::remove(&who);
+			<Users<T>>::remove(&who);
+			Self::deposit_event(Event::UserDeregistered(who));
+			Ok(())
+		}
 
-			Self::deposit_event(Event::UserCreated(who));
+		/// Bond `VouchBond` to vouch for a user, giving them `VouchRankBoost` rank points.
+		/// Part of the bond is burned if the user is later penalised.
+		///
+		/// **Call requirements**:
+		/// - Origin must be a user with at least `MinVoucherRank` rank points
+		/// - The vouchee must be a user nobody has vouched for
+		#[pallet::weight(T::WeightInfo::vouch())]
+		pub fn vouch(origin: OriginFor<T>, vouchee: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let voucher = <Users<T>>::get(&who).ok_or(Error::<T>::UserNotFound)?;
+			let mut user = <Users<T>>::get(&vouchee).ok_or(Error::<T>::UserNotFound)?;
+			let bond = T::VouchBond::get();
+			// CHECKS
+			ensure!(who.ne(&vouchee), Error::<T>::SelfVouch);
+			ensure!(voucher.rank_points >= T::MinVoucherRank::get(), Error::<T>::InsufficientRank);
+			ensure!(!<Vouches<T>>::contains_key(&vouchee), Error::<T>::AlreadyVouched);
+			// FALLIBLE MUTATIONS
+			T::Currency::reserve(&who, bond)?;
+			// MUTATIONS
+			user.rank_points = user.rank_points.saturating_add(T::VouchRankBoost::get());
+			// STORAGE MUTATIONS
+			<Users<T>>::insert(&vouchee, user);
+			<Vouches<T>>::insert(&vouchee, (who.clone(), bond));
+			Self::deposit_event(Event::Vouched(who, vouchee));
+			Ok(())
+		}
 
+		/// Withdraw a vouch, taking back its rank boost and releasing what is left of the bond.
+		///
+		/// **Call requirements**:
+		/// - Origin must have vouched for the user
+		/// - The vouchee must have no reviews awaiting judgement
+		#[pallet::weight(T::WeightInfo::unvouch())]
+		pub fn unvouch(origin: OriginFor<T>, vouchee: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let (voucher, bond) = <Vouches<T>>::get(&vouchee).ok_or(Error::<T>::NotVouched)?;
+			let mut user = <Users<T>>::get(&vouchee).ok_or(Error::<T>::UserNotFound)?;
+			// CHECKS
+			ensure!(voucher.eq(&who), Error::<T>::NotVoucher);
+			ensure!(user.open_reviews == 0, Error::<T>::OpenReviews);
+			// MUTATIONS
+			T::Currency::unreserve(&who, bond);
+			user.rank_points = user.rank_points.saturating_sub(T::VouchRankBoost::get()).max(1);
+			// STORAGE MUTATIONS
+			<Users<T>>::insert(&vouchee, user);
+			<Vouches<T>>::remove(&vouchee);
+			Self::deposit_event(Event::Unvouched(who, vouchee));
+			Ok(())
+		}
+
+		/// Link an Ethereum address to the caller. The signature is an `eth_sign` (personal_sign)
+		/// signature, by the Ethereum account, of `LinkPrefix` followed by the hex of the caller's
+		/// SCALE-encoded account id. A user has at most one address and an address one user.
+		///
+		/// **Call requirements**:
+		/// - Origin must be a registered user without a linked address
+		#[pallet::weight(T::WeightInfo::link_eth_address())]
+		pub fn link_eth_address(
+			origin: OriginFor<T>,
+			signature: ecdsa::Signature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let message = who.using_encoded(to_ascii_hex);
+			let address = Pallet::<T>::eth_recover(&signature, &message)
+				.ok_or(Error::<T>::InvalidEthSignature)?;
+			// CHECKS
+			ensure!(Users::<T>::contains_key(&who), Error::<T>::UserNotFound);
+			ensure!(!<EthAddresses<T>>::contains_key(&who), Error::<T>::EthAddressAlreadySet);
+			ensure!(!<EthOwners<T>>::contains_key(address), Error::<T>::EthAddressTaken);
+			// STORAGE MUTATIONS
+			<EthAddresses<T>>::insert(&who, address);
+			<EthOwners<T>>::insert(address, &who);
+			Self::deposit_event(Event::EthAddressLinked(who, address));
+			Ok(())
+		}
+
+		/// Remove the caller's Ethereum link, freeing the address to be linked again.
+		#[pallet::weight(T::WeightInfo::unlink_eth_address())]
+		pub fn unlink_eth_address(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let address = <EthAddresses<T>>::get(&who).ok_or(Error::<T>::NoEthAddress)?;
+			// STORAGE MUTATIONS
+			<EthAddresses<T>>::remove(&who);
+			<EthOwners<T>>::remove(address);
+			Self::deposit_event(Event::EthAddressUnlinked(who, address));
+			Ok(())
+		}
+
+		/// Set or replace the caller's profile. The username is normalised to lowercase and
+		/// must not be held by anyone else. Reserves `DataDepositPerByte` for each byte of the
+		/// encoded profile, releasing the deposit of the profile it replaces.
+		///
+		/// **Call requirements**:
+		/// - Origin must be a registered user
+		#[pallet::weight(T::WeightInfo::set_profile())]
+		pub fn set_profile(origin: OriginFor<T>, profile: UserProfileOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let username: UsernameOf<T> = normalise_username(&profile.username)
+				.and_then(|username| username.try_into().ok())
+				.ok_or(Error::<T>::InvalidUsername)?;
+			let profile = UserProfile { username: username.clone(), ..profile };
+			let length = BalanceOf::<T>::from(profile.encoded_size() as u32);
+			let deposit = T::DataDepositPerByte::get().saturating_mul(length);
+			let previous = <Profiles<T>>::get(&who);
+			// CHECKS
+			ensure!(Users::<T>::contains_key(&who), Error::<T>::UserNotFound);
+			profile.validate().map_err(Error::<T>::from)?;
+			if let Some(holder) = <Usernames<T>>::get(&username) {
+				ensure!(holder == who, Error::<T>::UsernameTaken);
+			}
+			// FALLIBLE MUTATIONS
+			T::Currency::reserve(&who, deposit)?;
+			if let Some((previous, previous_deposit)) = previous {
+				T::Currency::unreserve(&who, previous_deposit);
+				<Usernames<T>>::remove(&previous.username);
+			}
+			// STORAGE MUTATIONS
+			<Usernames<T>>::insert(&username, &who);
+			<Profiles<T>>::insert(&who, (profile, deposit));
+			Self::deposit_event(Event::ProfileSet(who, username));
+			Ok(())
+		}
+
+		/// Remove the caller's profile, freeing their username and releasing its deposit.
+		#[pallet::weight(T::WeightInfo::clear_profile())]
+		pub fn clear_profile(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// VALUES
+			let (profile, deposit) = <Profiles<T>>::get(&who).ok_or(Error::<T>::NoProfile)?;
+			// MUTATIONS
+			T::Currency::unreserve(&who, deposit);
+			// STORAGE MUTATIONS
+			<Usernames<T>>::remove(&profile.username);
+			<Profiles<T>>::remove(&who);
+			Self::deposit_event(Event::ProfileCleared(who, profile.username));
 			Ok(())
 		}
 	}
+	impl<T: Config> Pallet<T> {
+		/// The message an Ethereum wallet hashes when asked to `eth_sign` `what`, prefixed by
+		/// `LinkPrefix`.
+		pub fn eth_signable_message(what: &[u8]) -> Vec<u8> {
+			let prefix = T::LinkPrefix::get();
+			let mut length = prefix.len() + what.len();
+			let mut digits = Vec::new();
+			while length > 0 {
+				digits.push(b'0' + (length % 10) as u8);
+				length /= 10;
+			}
+			let mut message = b"\x19Ethereum Signed Message:\n".to_vec();
+			message.extend(digits.into_iter().rev());
+			message.extend_from_slice(prefix);
+			message.extend_from_slice(what);
+			message
+		}
+
+		/// The Ethereum address that signed `what`, prefixed by `LinkPrefix`.
+		pub fn eth_recover(signature: &ecdsa::Signature, what: &[u8]) -> Option<H160> {
+			let hash = keccak_256(&Pallet::<T>::eth_signable_message(what));
+			let public = secp256k1_ecdsa_recover(signature.as_ref(), &hash).ok()?;
+			Some(H160::from_slice(&keccak_256(&public)[12..]))
+		}
+
+		/// Reserve the registration deposit and store the user. Does not check the user is new.
+		pub fn register(who: &T::AccountId, user: User) -> DispatchResult {
+			let deposit = T::RegistrationDeposit::get();
+			// FALLIBLE MUTATIONS
+			T::Currency::reserve(who, deposit)?;
+			// STORAGE MUTATIONS
+			<RegistrationDeposits<T>>::insert(who, deposit);
+			<Users<T>>::insert(who, user);
+			Self::deposit_event(Event::UserCreated(who.clone()));
+			Ok(())
+		}
+	}
+
 	impl<T: Config> UserIO<T> for Pallet<T> {
 		fn get_user_by_id(id: &T::AccountId) -> Option<User> {
 			self::Users::<T>::get(id)
@@ -82,20 +445,19 @@ pub mod pallet {
 		fn check_user_exists(id: &T::AccountId) -> bool {
 			self::Users::<T>::contains_key(id)
 		}
-		fn get_or_create_default(id: &T::AccountId) -> User {
-			let user_exists = Self::check_user_exists(&id);
-			let user = Self::get_user_by_id(&id).unwrap_or_default();
-			if !user_exists {
-				self::Users::<T>::insert(&id, user.clone());
+		fn get_or_create_default(id: &T::AccountId) -> Result<User, DispatchError> {
+			if let Some(user) = Self::get_user_by_id(&id) {
+				return Ok(user);
 			}
-			user
+			let user = User::default();
+			Self::register(id, user.clone())?;
+			Ok(user)
 		}
-		fn set_user(id: &T::AccountId, user: User) -> () {
+		fn set_user(id: &T::AccountId, user: User) -> DispatchResult {
 			if Self::check_user_exists(id) {
-				return ();
+				return Ok(());
 			}
-			<Users<T>>::insert(id, user);
-			()
+			Self::register(id, user)
 		}
 		fn update_user(id: &T::AccountId, user: User) -> DispatchResult {
 			if !Self::check_user_exists(id) {
@@ -104,5 +466,41 @@ pub mod pallet {
 			<Users<T>>::mutate(id, |u| *u = Some(user));
 			Ok(())
 		}
+		fn username_of(id: &T::AccountId) -> Option<Vec<u8>> {
+			<Profiles<T>>::get(id).map(|(profile, _)| profile.username.into_inner())
+		}
+		fn account_of_username(username: &[u8]) -> Option<T::AccountId> {
+			let username: UsernameOf<T> = normalise_username(username)?.try_into().ok()?;
+			<Usernames<T>>::get(username)
+		}
+		fn eth_address_of(id: &T::AccountId) -> Option<H160> {
+			<EthAddresses<T>>::get(id)
+		}
+		fn penalise(id: &T::AccountId) -> DispatchResult {
+			let mut user = <Users<T>>::get(id).ok_or(Error::<T>::UserNotFound)?;
+			// Rank never drops below 1, see `User::default`.
+			user.rank_points = user.rank_points.saturating_sub(T::PenaltyRankPoints::get()).max(1);
+			<Users<T>>::insert(id, user);
+			if let Some((voucher, bond)) = <Vouches<T>>::get(id) {
+				let slash = T::VouchSlash::get() * bond;
+				let (_, unslashed) = T::Currency::slash_reserved(&voucher, slash);
+				let slashed = slash.saturating_sub(unslashed);
+				<Vouches<T>>::insert(id, (voucher.clone(), bond.saturating_sub(slashed)));
+				Self::deposit_event(Event::VoucherSlashed(voucher, id.clone(), slashed));
+			}
+			Self::deposit_event(Event::Penalised(id.clone()));
+			Ok(())
+		}
+	}
+}
+
+/// Lowercase hex of the bytes, without a `0x` prefix.
+fn to_ascii_hex(data: &[u8]) -> sp_std::vec::Vec<u8> {
+	let mut hex = sp_std::vec::Vec::with_capacity(data.len() * 2);
+	let mut push_nibble = |n| hex.push(if n < 10 { b'0' + n } else { b'a' - 10 + n });
+	for &byte in data.iter() {
+		push_nibble(byte / 16);
+		push_nibble(byte % 16);
 	}
+	hex
 }
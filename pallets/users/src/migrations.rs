@@ -0,0 +1,64 @@
+//! Storage migrations of the users pallet.
+
+use crate::{Config, Pallet, Users};
+use chocolate_primitives::users::User;
+use codec::{Decode, Encode};
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// The most users a migration translates. Keeps each migration well within the weight of a
+/// block.
+pub const MAX_MIGRATED_USERS: usize = 1_000;
+
+/// Version 1 adds `open_reviews` to `User`, starting at zero. Users created before it paid no
+/// registration deposit, so their deposit stays at zero.
+pub mod v1 {
+	use super::*;
+
+	/// `User` before version 1.
+	#[derive(Encode, Decode)]
+	pub struct OldUser {
+		pub rank_points: u32,
+		pub project_id: Option<u32>,
+	}
+
+	/// Migrate `Users` to version 1. Must run before the chocolate pallet's migration to
+	/// version 9, which counts the users' open reviews.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+			let mut users: Weight = 0;
+			<Users<T>>::translate::<OldUser, _>(|_, old| {
+				users = users.saturating_add(1);
+				let OldUser { rank_points, project_id } = old;
+				Some(User { rank_points, project_id, open_reviews: 0 })
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(users.saturating_add(1), users.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			if Pallet::<T>::on_chain_storage_version() < 1 {
+				let users = <Users<T>>::iter_keys().count();
+				frame_support::ensure!(users <= MAX_MIGRATED_USERS, "Too many users to migrate");
+			}
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"Migration did not run"
+			);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,120 @@
+use crate as pallet_users;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Everything},
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		UsersModule: pallet_users::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+	pub const ExistentialDeposit: u64 = 1;
+	pub const RegistrationDeposit: u64 = REGISTRATION_DEPOSIT;
+	pub const DataDepositPerByte: u64 = 1;
+	pub const VouchBond: u64 = VOUCH_BOND;
+	pub const MinVoucherRank: u32 = 3;
+	pub const VouchRankBoost: u32 = 2;
+	pub const PenaltyRankPoints: u32 = 2;
+	pub const VouchSlash: Perbill = Perbill::from_percent(50);
+	pub const LinkPrefix: &'static [u8] = b"Link to Chocolate account:";
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+impl pallet_users::Config for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type StringLimit = ConstU32<128>;
+	type RegistrationDeposit = RegistrationDeposit;
+	type DataDepositPerByte = DataDepositPerByte;
+	type VouchBond = VouchBond;
+	type MinVoucherRank = MinVoucherRank;
+	type VouchRankBoost = VouchRankBoost;
+	type PenaltyRankPoints = PenaltyRankPoints;
+	type VouchSlash = VouchSlash;
+	type LinkPrefix = LinkPrefix;
+	type WeightInfo = ();
+}
+
+/// Starting balance of accounts 1 to 3.
+pub const ENDOWMENT: u64 = 1_000;
+/// Deposit reserved when a user is created.
+pub const REGISTRATION_DEPOSIT: u64 = 10;
+/// Bond reserved from a voucher for each user they vouch for.
+pub const VOUCH_BOND: u64 = 100;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		// account 4 can pay the registration deposit but little else, account 5 has nothing
+		balances: (1..=3)
+			.map(|who| (who, ENDOWMENT))
+			.chain([(4, 2 * REGISTRATION_DEPOSIT)])
+			.collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
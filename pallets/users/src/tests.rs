@@ -0,0 +1,378 @@
+use crate::{
+	migrations::v1::{MigrateToV1, OldUser},
+	mock::*, to_ascii_hex, Error, EthAddresses, EthOwners, Event as UsersEvent, Profiles,
+	RegistrationDeposits, UserProfileOf, Usernames, Users, Vouches,
+};
+use chocolate_primitives::users::{User, UserIO, UserProfile};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+	BoundedVec,
+};
+use hex_literal::hex;
+use sp_core::{ecdsa, H160};
+use sp_io::hashing::keccak_256;
+
+const AVATAR: &[u8] = b"QmPAVb28J73KjzcjTQ85oJLyftmm4kEgTTptRssPnKjKZD";
+
+fn bounded(bytes: &[u8]) -> BoundedVec<u8, frame_support::traits::ConstU32<128>> {
+	bytes.to_vec().try_into().expect("Should be within string limit")
+}
+
+fn profile(username: &[u8]) -> UserProfileOf<Test> {
+	UserProfile {
+		username: bounded(username),
+		display_name: bounded("Alice Ł".as_bytes()),
+		avatar: Some(bounded(AVATAR)),
+		bio: None,
+	}
+}
+
+fn eth_secret(seed: &[u8]) -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&keccak_256(seed)).unwrap()
+}
+
+fn eth_address(secret: &libsecp256k1::SecretKey) -> H160 {
+	let public = libsecp256k1::PublicKey::from_secret_key(secret).serialize();
+	H160::from_slice(&keccak_256(&public[1..65])[12..])
+}
+
+/// The `eth_sign` signature an Ethereum wallet holding `secret` makes to link to `who`.
+fn eth_sign(who: u64, secret: &libsecp256k1::SecretKey) -> ecdsa::Signature {
+	let message = UsersModule::eth_signable_message(&who.using_encoded(to_ascii_hex));
+	let hash = libsecp256k1::Message::parse(&keccak_256(&message));
+	let (signature, recovery_id) = libsecp256k1::sign(&hash, secret);
+	let mut raw = [0u8; 65];
+	raw[..64].copy_from_slice(&signature.serialize());
+	raw[64] = recovery_id.serialize();
+	ecdsa::Signature::from_raw(raw)
+}
+
+#[test]
+fn make_user_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(UsersModule::make_user(Origin::signed(1)));
+		assert_eq!(
+			Users::<Test>::get(1),
+			Some(User { rank_points: 1, project_id: None, open_reviews: 0 })
+		);
+		assert_eq!(RegistrationDeposits::<Test>::get(1), REGISTRATION_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(1), REGISTRATION_DEPOSIT);
+		System::assert_last_event(UsersEvent::UserCreated(1).into());
+	});
+}
+
+#[test]
+fn make_user_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(UsersModule::make_user(Origin::signed(1)));
+		assert_noop!(UsersModule::make_user(Origin::signed(1)), Error::<Test>::UserAlreadyExists);
+		assert_noop!(
+			UsersModule::make_user(Origin::signed(5)),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn deregister_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(UsersModule::make_user(Origin::signed(1)));
+		assert_ok!(UsersModule::set_profile(Origin::signed(1), profile(b"alice")));
+		assert_ok!(UsersModule::deregister(Origin::signed(1)));
+		assert!(!UsersModule::check_user_exists(&1));
+		assert!(!RegistrationDeposits::<Test>::contains_key(1));
+		assert_eq!(Profiles::<Test>::get(1), None);
+		assert_eq!(UsersModule::account_of_username(b"alice"), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), ENDOWMENT);
+		System::assert_last_event(UsersEvent::UserDeregistered(1).into());
+	});
+}
+
+#[test]
+fn deregister_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(UsersModule::deregister(Origin::signed(1)), Error::<Test>::UserNotFound);
+		let user = UsersModule::get_or_create_default(&1).unwrap();
+		assert_ok!(UsersModule::update_user(&1, User { open_reviews: 1, ..user.clone() }));
+		assert_noop!(UsersModule::deregister(Origin::signed(1)), Error::<Test>::OpenReviews);
+		assert_ok!(UsersModule::update_user(&1, User { project_id: Some(1), ..user }));
+		assert_noop!(UsersModule::deregister(Origin::signed(1)), Error::<Test>::OwnsProject);
+	});
+}
+
+#[test]
+fn user_io_should_work() {
+	new_test_ext().execute_with(|| {
+		assert!(!UsersModule::check_user_exists(&1));
+		let user = UsersModule::get_or_create_default(&1).unwrap();
+		assert!(UsersModule::check_user_exists(&1));
+		assert_eq!(Balances::reserved_balance(1), REGISTRATION_DEPOSIT);
+		// implicit creation pays the same deposit as make_user
+		assert!(UsersModule::get_or_create_default(&5).is_err());
+		assert!(UsersModule::set_user(&5, User::default()).is_err());
+		assert!(!UsersModule::check_user_exists(&5));
+		assert!(!UsersModule::check_owns_project(&1));
+		assert!(UsersModule::update_user(&2, user.clone()).is_err());
+		let owner = User { project_id: Some(1), ..user };
+		assert_ok!(UsersModule::update_user(&1, owner.clone()));
+		assert_eq!(UsersModule::get_user_by_id(&1), Some(owner));
+		assert!(UsersModule::check_owns_project(&1));
+	});
+}
+
+#[test]
+fn set_profile_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(UsersModule::make_user(Origin::signed(1)));
+		assert_ok!(UsersModule::set_profile(Origin::signed(1), profile(b"Alice_1")));
+		let stored = profile(b"alice_1");
+		let deposit = stored.encoded_size() as u64;
+		assert_eq!(Profiles::<Test>::get(1), Some((stored, deposit)));
+		assert_eq!(Usernames::<Test>::get(bounded(b"alice_1")), Some(1));
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		System::assert_last_event(UsersEvent::ProfileSet(1, bounded(b"alice_1")).into());
+		assert_eq!(UsersModule::username_of(&1), Some(b"alice_1".to_vec()));
+		assert_eq!(UsersModule::account_of_username(b"ALICE_1"), Some(1));
+		// renaming frees the old username and swaps the deposit
+		let renamed = UserProfile { bio: Some(bounded(AVATAR)), ..profile(b"alicia") };
+		assert_ok!(UsersModule::set_profile(Origin::signed(1), renamed.clone()));
+		assert_eq!(Usernames::<Test>::get(bounded(b"alice_1")), None);
+		assert_eq!(UsersModule::account_of_username(b"alicia"), Some(1));
+		assert_eq!(Balances::reserved_balance(1), renamed.encoded_size() as u64);
+		// the old username can now be claimed
+		assert_ok!(UsersModule::make_user(Origin::signed(2)));
+		assert_ok!(UsersModule::set_profile(Origin::signed(2), profile(b"alice_1")));
+		// clearing frees the username and releases the deposit
+		assert_ok!(UsersModule::clear_profile(Origin::signed(1)));
+		assert_eq!(Profiles::<Test>::get(1), None);
+		assert_eq!(UsersModule::account_of_username(b"alicia"), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(UsersEvent::ProfileCleared(1, bounded(b"alicia")).into());
+	});
+}
+
+#[test]
+fn set_profile_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			UsersModule::set_profile(Origin::signed(1), profile(b"alice")),
+			Error::<Test>::UserNotFound
+		);
+		assert_ok!(UsersModule::make_user(Origin::signed(1)));
+		assert_ok!(UsersModule::make_user(Origin::signed(2)));
+		assert_ok!(UsersModule::make_user(Origin::signed(4)));
+		for (username, error) in [
+			(&b"al"[..], Error::<Test>::InvalidUsername),
+			(b"alice smith", Error::<Test>::InvalidUsername),
+			(b"alice-smith", Error::<Test>::InvalidUsername),
+		] {
+			assert_noop!(UsersModule::set_profile(Origin::signed(1), profile(username)), error);
+		}
+		let unnamed = UserProfile { display_name: bounded(b""), ..profile(b"alice") };
+		assert_noop!(
+			UsersModule::set_profile(Origin::signed(1), unnamed),
+			Error::<Test>::InvalidDisplayName
+		);
+		let broken_avatar =
+			UserProfile { avatar: Some(bounded(b"not-a-cid")), ..profile(b"alice") };
+		assert_noop!(
+			UsersModule::set_profile(Origin::signed(1), broken_avatar),
+			Error::<Test>::InvalidAvatar
+		);
+		let broken_bio = UserProfile { bio: Some(bounded(b"Qm")), ..profile(b"alice") };
+		assert_noop!(
+			UsersModule::set_profile(Origin::signed(1), broken_bio),
+			Error::<Test>::InvalidBio
+		);
+		// usernames are unique regardless of case
+		assert_ok!(UsersModule::set_profile(Origin::signed(1), profile(b"alice")));
+		assert_noop!(
+			UsersModule::set_profile(Origin::signed(2), profile(b"ALICE")),
+			Error::<Test>::UsernameTaken
+		);
+		// account 4 has nothing to reserve the deposit from
+		assert_noop!(
+			UsersModule::set_profile(Origin::signed(4), profile(b"dave")),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(UsersModule::clear_profile(Origin::signed(2)), Error::<Test>::NoProfile);
+	});
+}
+
+/// Account 1 with enough rank to vouch, and account 2 as a plain user.
+fn with_voucher() {
+	assert_ok!(UsersModule::make_user(Origin::signed(1)));
+	assert_ok!(UsersModule::make_user(Origin::signed(2)));
+	let user = UsersModule::get_user_by_id(&1).unwrap();
+	assert_ok!(UsersModule::update_user(&1, User { rank_points: 3, ..user }));
+}
+
+#[test]
+fn vouch_should_work() {
+	new_test_ext().execute_with(|| {
+		with_voucher();
+		assert_ok!(UsersModule::vouch(Origin::signed(1), 2));
+		assert_eq!(Vouches::<Test>::get(2), Some((1, VOUCH_BOND)));
+		assert_eq!(UsersModule::get_user_by_id(&2).unwrap().rank_points, 3);
+		assert_eq!(Balances::reserved_balance(1), REGISTRATION_DEPOSIT + VOUCH_BOND);
+		System::assert_last_event(UsersEvent::Vouched(1, 2).into());
+		assert_ok!(UsersModule::unvouch(Origin::signed(1), 2));
+		assert_eq!(Vouches::<Test>::get(2), None);
+		assert_eq!(UsersModule::get_user_by_id(&2).unwrap().rank_points, 1);
+		assert_eq!(Balances::reserved_balance(1), REGISTRATION_DEPOSIT);
+		System::assert_last_event(UsersEvent::Unvouched(1, 2).into());
+		// deregistering releases the bond too
+		assert_ok!(UsersModule::vouch(Origin::signed(1), 2));
+		assert_ok!(UsersModule::deregister(Origin::signed(2)));
+		assert_eq!(Vouches::<Test>::get(2), None);
+		assert_eq!(Balances::reserved_balance(1), REGISTRATION_DEPOSIT);
+	});
+}
+
+#[test]
+fn vouch_should_fail() {
+	new_test_ext().execute_with(|| {
+		with_voucher();
+		assert_noop!(UsersModule::vouch(Origin::signed(1), 3), Error::<Test>::UserNotFound);
+		assert_noop!(UsersModule::vouch(Origin::signed(1), 1), Error::<Test>::SelfVouch);
+		assert_noop!(UsersModule::vouch(Origin::signed(2), 1), Error::<Test>::InsufficientRank);
+		assert_noop!(UsersModule::unvouch(Origin::signed(1), 2), Error::<Test>::NotVouched);
+		assert_ok!(UsersModule::make_user(Origin::signed(4)));
+		let user = UsersModule::get_user_by_id(&4).unwrap();
+		assert_ok!(UsersModule::update_user(&4, User { rank_points: 3, ..user }));
+		assert_noop!(
+			UsersModule::vouch(Origin::signed(4), 2),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(UsersModule::vouch(Origin::signed(1), 2));
+		assert_noop!(UsersModule::vouch(Origin::signed(3), 2), Error::<Test>::UserNotFound);
+		assert_ok!(UsersModule::make_user(Origin::signed(3)));
+		let user = UsersModule::get_user_by_id(&3).unwrap();
+		assert_ok!(UsersModule::update_user(&3, User { rank_points: 3, ..user }));
+		assert_noop!(UsersModule::vouch(Origin::signed(3), 2), Error::<Test>::AlreadyVouched);
+		assert_noop!(UsersModule::unvouch(Origin::signed(3), 2), Error::<Test>::NotVoucher);
+		let user = UsersModule::get_user_by_id(&2).unwrap();
+		assert_ok!(UsersModule::update_user(&2, User { open_reviews: 1, ..user }));
+		assert_noop!(UsersModule::unvouch(Origin::signed(1), 2), Error::<Test>::OpenReviews);
+	});
+}
+
+#[test]
+fn penalise_should_slash_voucher() {
+	new_test_ext().execute_with(|| {
+		with_voucher();
+		assert_ok!(UsersModule::vouch(Origin::signed(1), 2));
+		let issuance = Balances::total_issuance();
+		assert_ok!(UsersModule::penalise(&2));
+		// VouchSlash is half the bond, and rank never drops below 1
+		assert_eq!(UsersModule::get_user_by_id(&2).unwrap().rank_points, 1);
+		assert_eq!(Vouches::<Test>::get(2), Some((1, VOUCH_BOND / 2)));
+		assert_eq!(Balances::reserved_balance(1), REGISTRATION_DEPOSIT + VOUCH_BOND / 2);
+		assert_eq!(Balances::total_issuance(), issuance - VOUCH_BOND / 2);
+		System::assert_has_event(UsersEvent::VoucherSlashed(1, 2, VOUCH_BOND / 2).into());
+		System::assert_last_event(UsersEvent::Penalised(2).into());
+		// users nobody vouched for only lose rank
+		assert_ok!(UsersModule::penalise(&1));
+		assert_eq!(UsersModule::get_user_by_id(&1).unwrap().rank_points, 1);
+		assert!(UsersModule::penalise(&3).is_err());
+	});
+}
+
+#[test]
+fn link_eth_address_should_work() {
+	new_test_ext().execute_with(|| {
+		let alice = eth_secret(b"Alice");
+		assert_ok!(UsersModule::make_user(Origin::signed(1)));
+		assert_ok!(UsersModule::link_eth_address(Origin::signed(1), eth_sign(1, &alice)));
+		assert_eq!(EthAddresses::<Test>::get(1), Some(eth_address(&alice)));
+		assert_eq!(EthOwners::<Test>::get(eth_address(&alice)), Some(1));
+		assert_eq!(UsersModule::eth_address_of(&1), Some(eth_address(&alice)));
+		System::assert_last_event(UsersEvent::EthAddressLinked(1, eth_address(&alice)).into());
+		// unlinking frees the address for another user
+		assert_ok!(UsersModule::unlink_eth_address(Origin::signed(1)));
+		assert_eq!(EthAddresses::<Test>::get(1), None);
+		assert_eq!(EthOwners::<Test>::get(eth_address(&alice)), None);
+		System::assert_last_event(UsersEvent::EthAddressUnlinked(1, eth_address(&alice)).into());
+		assert_ok!(UsersModule::make_user(Origin::signed(2)));
+		assert_ok!(UsersModule::link_eth_address(Origin::signed(2), eth_sign(2, &alice)));
+		// deregistering drops the link
+		assert_ok!(UsersModule::deregister(Origin::signed(2)));
+		assert_eq!(EthAddresses::<Test>::get(2), None);
+		assert_eq!(EthOwners::<Test>::get(eth_address(&alice)), None);
+	});
+}
+
+#[test]
+fn link_eth_address_should_accept_wallet_signatures() {
+	new_test_ext().execute_with(|| {
+		// personal_sign of "Link to Chocolate account:0100000000000000" by the well-known
+		// development key 0xac0974be…f2ff80, as wallets produce it: RFC 6979, low s, v of 27/28.
+		let signature = ecdsa::Signature::from_raw(hex!(
+			"fbb0edeb834e522786c921cef3fe5db00fb97e0b9f47a55059e668e6f3cb8256"
+			"2f76aaf7ff0ba7c216c78663dc0564ce5e09499744515bfcf5e1423a6cd69271"
+			"1b"
+		));
+		let address = H160::from(hex!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266"));
+		assert_ok!(UsersModule::make_user(Origin::signed(1)));
+		assert_ok!(UsersModule::link_eth_address(Origin::signed(1), signature));
+		assert_eq!(EthAddresses::<Test>::get(1), Some(address));
+	});
+}
+
+#[test]
+fn link_eth_address_should_fail() {
+	new_test_ext().execute_with(|| {
+		let alice = eth_secret(b"Alice");
+		let bob = eth_secret(b"Bob");
+		assert_noop!(
+			UsersModule::link_eth_address(Origin::signed(1), eth_sign(1, &alice)),
+			Error::<Test>::UserNotFound
+		);
+		assert_ok!(UsersModule::make_user(Origin::signed(1)));
+		assert_ok!(UsersModule::make_user(Origin::signed(2)));
+		assert_noop!(
+			UsersModule::link_eth_address(Origin::signed(1), ecdsa::Signature::from_raw([0; 65])),
+			Error::<Test>::InvalidEthSignature
+		);
+		assert_noop!(
+			UsersModule::unlink_eth_address(Origin::signed(1)),
+			Error::<Test>::NoEthAddress
+		);
+		// a signature made for another account recovers some other address
+		assert_ok!(UsersModule::link_eth_address(Origin::signed(1), eth_sign(2, &alice)));
+		assert_ne!(EthAddresses::<Test>::get(1), Some(eth_address(&alice)));
+		assert_ok!(UsersModule::unlink_eth_address(Origin::signed(1)));
+		assert_ok!(UsersModule::link_eth_address(Origin::signed(1), eth_sign(1, &alice)));
+		assert_noop!(
+			UsersModule::link_eth_address(Origin::signed(1), eth_sign(1, &bob)),
+			Error::<Test>::EthAddressAlreadySet
+		);
+		assert_noop!(
+			UsersModule::link_eth_address(Origin::signed(2), eth_sign(2, &alice)),
+			Error::<Test>::EthAddressTaken
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v1_should_work() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<UsersModule>();
+		let old = OldUser { rank_points: 7, project_id: Some(3) };
+		unhashed::put(&Users::<Test>::hashed_key_for(1), &old);
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		let migrated = User { rank_points: 7, project_id: Some(3), open_reviews: 0 };
+		assert_eq!(Users::<Test>::get(1), Some(migrated.clone()));
+		assert_eq!(RegistrationDeposits::<Test>::get(1), 0);
+		assert_eq!(UsersModule::on_chain_storage_version(), 1);
+		// Already migrated users are left alone.
+		unhashed::put(&Users::<Test>::hashed_key_for(2), &old);
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Users::<Test>::get(1), Some(migrated));
+		assert_eq!(Users::<Test>::get(2), None);
+	});
+}
@@ -0,0 +1,179 @@
+//! Weights for pallet_users
+//!
+//! Hand-written until the benchmarks have been run: calls pay for their worst-case storage
+//! accesses at `DbWeight` plus a flat `EXECUTION` allowance, and `link_eth_address` also pays
+//! `ECDSA_RECOVER` for recovering the signer, which is an estimate, not a measurement.
+//! Generate the real weights on reference hardware from the repository root with:
+//!
+//! ./scripts/benchmark.sh users
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Flat allowance for the computation of a call, on top of its storage accesses.
+pub const EXECUTION: Weight = 50_000_000;
+/// Allowance for recovering an Ethereum signer from a secp256k1 signature.
+pub const ECDSA_RECOVER: Weight = 50_000_000;
+
+/// Weight functions needed for pallet_users.
+pub trait WeightInfo {
+	fn make_user() -> Weight;
+	fn deregister() -> Weight;
+	fn vouch() -> Weight;
+	fn unvouch() -> Weight;
+	fn link_eth_address() -> Weight;
+	fn unlink_eth_address() -> Weight;
+	fn set_profile() -> Weight;
+	fn clear_profile() -> Weight;
+}
+
+/// Weights for pallet_users, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Users Users (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Users RegistrationDeposits (r:0 w:1)
+	fn make_user() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Users Users (r:1 w:1)
+	// Storage: Users RegistrationDeposits (r:1 w:1)
+	// Storage: Users Profiles (r:1 w:1)
+	// Storage: Users Vouches (r:1 w:1)
+	// Storage: Users EthAddresses (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Users Usernames (r:0 w:1)
+	// Storage: Users EthOwners (r:0 w:1)
+	fn deregister() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Users Users (r:2 w:1)
+	// Storage: Users Vouches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vouch() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Users Vouches (r:1 w:1)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unvouch() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Users Users (r:1 w:0)
+	// Storage: Users EthAddresses (r:1 w:1)
+	// Storage: Users EthOwners (r:1 w:1)
+	fn link_eth_address() -> Weight {
+		EXECUTION.saturating_add(ECDSA_RECOVER)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Users EthAddresses (r:1 w:1)
+	// Storage: Users EthOwners (r:0 w:1)
+	fn unlink_eth_address() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Users Profiles (r:1 w:1)
+	// Storage: Users Usernames (r:1 w:2)
+	// Storage: Users Users (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn set_profile() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Users Profiles (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Users Usernames (r:0 w:1)
+	fn clear_profile() -> Weight {
+		EXECUTION
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Users Users (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Users RegistrationDeposits (r:0 w:1)
+	fn make_user() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Users Users (r:1 w:1)
+	// Storage: Users RegistrationDeposits (r:1 w:1)
+	// Storage: Users Profiles (r:1 w:1)
+	// Storage: Users Vouches (r:1 w:1)
+	// Storage: Users EthAddresses (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Users Usernames (r:0 w:1)
+	// Storage: Users EthOwners (r:0 w:1)
+	fn deregister() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Users Users (r:2 w:1)
+	// Storage: Users Vouches (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vouch() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Users Vouches (r:1 w:1)
+	// Storage: Users Users (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unvouch() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Users Users (r:1 w:0)
+	// Storage: Users EthAddresses (r:1 w:1)
+	// Storage: Users EthOwners (r:1 w:1)
+	fn link_eth_address() -> Weight {
+		EXECUTION.saturating_add(ECDSA_RECOVER)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Users EthAddresses (r:1 w:1)
+	// Storage: Users EthOwners (r:0 w:1)
+	fn unlink_eth_address() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Users Profiles (r:1 w:1)
+	// Storage: Users Usernames (r:1 w:2)
+	// Storage: Users Users (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn set_profile() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Users Profiles (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Users Usernames (r:0 w:1)
+	fn clear_profile() -> Weight {
+		EXECUTION
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
@@ -0,0 +1,264 @@
+//! `no_std` validation of IPFS content identifiers (CIDs).
+//!
+//! Accepts CIDv0 (base58btc `Qm...`) and CIDv1 in the base16, base32, base36 and base58btc
+//! multibase encodings, optionally followed by a path such as `/review1.json`.
+use super::*;
+use sp_std::vec::Vec;
+
+/// The longest CID, excluding any path, that will be decoded. Bounds decoding work.
+pub const MAX_CID_LENGTH: usize = 128;
+
+/// Multihash code of sha2-256, the only hash a CIDv0 can use.
+const SHA2_256: u64 = 0x12;
+
+const BASE58_BTC: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE36_LOWER: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE36_UPPER: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const BASE32_LOWER: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+const BASE32_UPPER: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Reasons a CID can be rejected.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+pub enum CidError {
+	/// Nothing to parse.
+	Empty,
+	/// The CID is longer than `MAX_CID_LENGTH`.
+	TooLong,
+	/// The multibase prefix is unknown or unsupported.
+	UnsupportedBase,
+	/// A character is outside the alphabet of the base.
+	InvalidEncoding,
+	/// The version is neither 0 nor 1.
+	UnsupportedVersion,
+	/// The multihash is truncated or its length does not match its digest.
+	InvalidMultihash,
+	/// The path has empty segments or non-printable characters.
+	InvalidPath,
+}
+
+/// A parsed CID.
+#[derive(Eq, PartialEq, Copy, Clone, RuntimeDebug)]
+pub struct Cid {
+	/// 0 or 1.
+	pub version: u8,
+	/// Multicodec of the content, `dag-pb` (0x70) for every CIDv0.
+	pub codec: u64,
+	/// Multihash code of the digest.
+	pub hash_code: u64,
+	/// Length of the digest in bytes.
+	pub digest_len: u64,
+}
+
+/// Parse a CID with an optional path suffix.
+pub fn parse(input: &[u8]) -> Result<Cid, CidError> {
+	let (cid, path) = match input.iter().position(|c| *c == b'/') {
+		Some(index) => (&input[..index], Some(&input[index + 1..])),
+		None => (input, None),
+	};
+	if let Some(path) = path {
+		validate_path(path)?;
+	}
+	if cid.is_empty() {
+		return Err(CidError::Empty)
+	}
+	if cid.len() > MAX_CID_LENGTH {
+		return Err(CidError::TooLong)
+	}
+	// CIDv0 is a bare base58btc sha2-256 multihash, always 46 characters starting with `Qm`.
+	if cid.len() == 46 && cid.starts_with(b"Qm") {
+		let bytes = decode_base_n(cid, BASE58_BTC)?;
+		let (hash_code, digest_len) = parse_multihash(&bytes)?;
+		if hash_code != SHA2_256 || digest_len != 32 {
+			return Err(CidError::InvalidMultihash)
+		}
+		return Ok(Cid { version: 0, codec: 0x70, hash_code, digest_len })
+	}
+	let bytes = match cid[0] {
+		b'z' => decode_base_n(&cid[1..], BASE58_BTC)?,
+		b'k' => decode_base_n(&cid[1..], BASE36_LOWER)?,
+		b'K' => decode_base_n(&cid[1..], BASE36_UPPER)?,
+		b'b' => decode_base32(&cid[1..], BASE32_LOWER)?,
+		b'B' => decode_base32(&cid[1..], BASE32_UPPER)?,
+		b'f' | b'F' => decode_base16(&cid[1..])?,
+		_ => return Err(CidError::UnsupportedBase),
+	};
+	let (version, rest) = read_varint(&bytes).ok_or(CidError::UnsupportedVersion)?;
+	if version != 1 {
+		return Err(CidError::UnsupportedVersion)
+	}
+	let (codec, rest) = read_varint(rest).ok_or(CidError::InvalidMultihash)?;
+	let (hash_code, digest_len) = parse_multihash(rest)?;
+	Ok(Cid { version: 1, codec, hash_code, digest_len })
+}
+
+/// Check that the input is a CID with an optional path suffix.
+pub fn validate(input: &[u8]) -> Result<(), CidError> {
+	parse(input).map(|_| ())
+}
+
+/// Non-empty segments of printable ascii. A single trailing slash is allowed, including right
+/// after the CID.
+fn validate_path(path: &[u8]) -> Result<(), CidError> {
+	if path.is_empty() {
+		return Ok(())
+	}
+	let path = path.strip_suffix(b"/").unwrap_or(path);
+	let valid = path
+		.split(|c| *c == b'/')
+		.all(|segment| !segment.is_empty() && segment.iter().all(|c| c.is_ascii_graphic()));
+	if valid {
+		Ok(())
+	} else {
+		Err(CidError::InvalidPath)
+	}
+}
+
+/// Returns the hash code and digest length of a multihash spanning all of `bytes`.
+fn parse_multihash(bytes: &[u8]) -> Result<(u64, u64), CidError> {
+	let (hash_code, rest) = read_varint(bytes).ok_or(CidError::InvalidMultihash)?;
+	let (digest_len, digest) = read_varint(rest).ok_or(CidError::InvalidMultihash)?;
+	if digest.len() as u64 != digest_len {
+		return Err(CidError::InvalidMultihash)
+	}
+	Ok((hash_code, digest_len))
+}
+
+/// Read an unsigned LEB128 varint of at most 9 bytes, as used by multiformats.
+/// Returns the value and the remaining bytes.
+fn read_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+	let mut value: u64 = 0;
+	for (index, byte) in bytes.iter().enumerate().take(9) {
+		value |= u64::from(byte & 0x7f) << (7 * index);
+		if byte & 0x80 == 0 {
+			return Some((value, &bytes[index + 1..]))
+		}
+	}
+	None
+}
+
+/// Decode a big-endian number written in the given alphabet, keeping leading zeroes.
+fn decode_base_n(input: &[u8], alphabet: &[u8]) -> Result<Vec<u8>, CidError> {
+	let base = alphabet.len() as u32;
+	let zero = alphabet[0];
+	let leading_zeroes = input.iter().take_while(|c| **c == zero).count();
+	// Little-endian base 256 digits.
+	let mut digits: Vec<u8> = Vec::with_capacity(input.len());
+	for c in input {
+		let mut carry =
+			alphabet.iter().position(|a| a == c).ok_or(CidError::InvalidEncoding)? as u32;
+		for digit in digits.iter_mut() {
+			carry += u32::from(*digit) * base;
+			*digit = (carry & 0xff) as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			digits.push((carry & 0xff) as u8);
+			carry >>= 8;
+		}
+	}
+	let mut bytes = sp_std::vec![0u8; leading_zeroes];
+	bytes.extend(digits.iter().rev());
+	Ok(bytes)
+}
+
+/// Decode unpadded RFC 4648 base32. Leftover bits must be zero.
+fn decode_base32(input: &[u8], alphabet: &[u8]) -> Result<Vec<u8>, CidError> {
+	let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+	let mut buffer: u32 = 0;
+	let mut bits = 0;
+	for c in input {
+		let value = alphabet.iter().position(|a| a == c).ok_or(CidError::InvalidEncoding)?;
+		buffer = (buffer << 5) | value as u32;
+		bits += 5;
+		if bits >= 8 {
+			bits -= 8;
+			bytes.push((buffer >> bits) as u8);
+			buffer &= (1 << bits) - 1;
+		}
+	}
+	if bits >= 5 || buffer != 0 {
+		return Err(CidError::InvalidEncoding)
+	}
+	Ok(bytes)
+}
+
+/// Decode base16 of either case.
+fn decode_base16(input: &[u8]) -> Result<Vec<u8>, CidError> {
+	if input.len() % 2 != 0 {
+		return Err(CidError::InvalidEncoding)
+	}
+	let nibble = |c: u8| match c {
+		b'0'..=b'9' => Ok(c - b'0'),
+		b'a'..=b'f' => Ok(c - b'a' + 10),
+		b'A'..=b'F' => Ok(c - b'A' + 10),
+		_ => Err(CidError::InvalidEncoding),
+	};
+	input.chunks(2).map(|pair| Ok((nibble(pair[0])? << 4) | nibble(pair[1])?)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_cid_v0() {
+		let cid = parse(b"QmPAVb28J73KjzcjTQ85oJLyftmm4kEgTTptRssPnKjKZD").unwrap();
+		assert_eq!(cid, Cid { version: 0, codec: 0x70, hash_code: SHA2_256, digest_len: 32 });
+	}
+
+	#[test]
+	fn parses_cid_v1() {
+		// The same dag-pb content as base32, base58btc and base16.
+		let base32 = parse(b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi").unwrap();
+		assert_eq!(base32, Cid { version: 1, codec: 0x70, hash_code: SHA2_256, digest_len: 32 });
+		let base58 = parse(b"zdj7WhuEjrB52m1BisYCtmjH1hSKa7yZ3jEZ9JcXaFRD51wVz").unwrap();
+		assert_eq!(base58.version, 1);
+		let base16 = parse(
+			b"f01701220c3c4733ec8affd06cf9e9ff50ffc6bcd2ec85a6170004bb709669c31de94391a",
+		)
+		.unwrap();
+		assert_eq!(base16, base32);
+		// raw codec, as produced by `ipfs add --cid-version 1` for small files.
+		let raw = parse(b"bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku").unwrap();
+		assert_eq!(raw.codec, 0x55);
+	}
+
+	#[test]
+	fn parses_paths() {
+		assert!(validate(b"QmdKx4pmnJUP5GdjtpJE2ei4xeaRKQWYwvXGuVY1AbAwDM/review1.json").is_ok());
+		assert!(validate(b"QmdKx4pmnJUP5GdjtpJE2ei4xeaRKQWYwvXGuVY1AbAwDM/a/b/").is_ok());
+		assert!(validate(b"QmdKx4pmnJUP5GdjtpJE2ei4xeaRKQWYwvXGuVY1AbAwDM/").is_ok());
+		assert_eq!(
+			validate(b"QmdKx4pmnJUP5GdjtpJE2ei4xeaRKQWYwvXGuVY1AbAwDM//"),
+			Err(CidError::InvalidPath)
+		);
+		assert_eq!(
+			validate(b"QmdKx4pmnJUP5GdjtpJE2ei4xeaRKQWYwvXGuVY1AbAwDM//b"),
+			Err(CidError::InvalidPath)
+		);
+		assert_eq!(
+			validate(b"QmdKx4pmnJUP5GdjtpJE2ei4xeaRKQWYwvXGuVY1AbAwDM/a b"),
+			Err(CidError::InvalidPath)
+		);
+	}
+
+	#[test]
+	fn rejects_invalid_cids() {
+		assert_eq!(validate(b""), Err(CidError::Empty));
+		assert_eq!(validate(b"/review.json"), Err(CidError::Empty));
+		assert_eq!(validate(&[42_u8]), Err(CidError::UnsupportedBase));
+		// 0 is not in the base58btc alphabet.
+		assert_eq!(
+			validate(b"Qm0AVb28J73KjzcjTQ85oJLyftmm4kEgTTptRssPnKjKZD"),
+			Err(CidError::InvalidEncoding)
+		);
+		// Truncated digest.
+		assert_eq!(
+			validate(b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbz"),
+			Err(CidError::InvalidMultihash)
+		);
+		// Version 2.
+		assert_eq!(validate(b"f02701220c3c4733e"), Err(CidError::UnsupportedVersion));
+		assert_eq!(validate(&[b'z'; MAX_CID_LENGTH + 1]), Err(CidError::TooLong));
+	}
+}
@@ -2,7 +2,7 @@
 
 // TL-imports to make mod lives easier.
 use frame_support::{
-	dispatch::DispatchResult,
+	dispatch::{DispatchError, DispatchResult},
 	sp_runtime::traits::Zero,
 	traits::{Get,tokens::Balance as BalanceTrait},
 	BoundedVec, RuntimeDebug,
@@ -14,5 +14,6 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use serde::{Deserialize, Serialize};
 
 
+pub mod cid;
 pub mod projects;
 pub mod users;
\ No newline at end of file
@@ -16,17 +16,255 @@ where
 	pub user_id: UserID,
 	pub content: BoundedVec<u8, StringLen>,
 	pub project_id: ProjectID,
+	/// The project's `metadata_version` at the time of review
+	pub metadata_version: u32,
 	/// A snapshot of the user's rank at the time of review
 	pub point_snapshot: u32,
 	/// Score of a review
 	pub review_score: u8,
+	/// Scores of the review per `RatingDimension`
+	pub dimension_scores: DimensionScores,
+	/// Number of times the review has been edited
+	pub edit_count: u32,
+	/// Whether the review was committed in a review round. Revealed reviews can't be edited.
+	pub revealed: bool,
+	/// Number of users who found the review helpful
+	pub helpful_votes: u32,
+	/// Number of users who found the review unhelpful
+	pub unhelpful_votes: u32,
+	/// Rank points the author has earned from the review's helpfulness
+	pub helpfulness_points: u32,
+	/// The author's earlier accepted review of the project that this one replaces once accepted
+	pub supersedes: Option<ReviewID>,
 	/// Currency the user provided for collateral
 	pub collateral_currency_id: CurrencyIdAlias,
 }
 
+/// The lowest score a review can give.
+pub const MIN_SCORE: u8 = 1;
+/// The highest score a review can give.
+pub const MAX_SCORE: u8 = 5;
+/// The number of `RatingDimension`s.
+pub const NUMBER_OF_DIMENSIONS: usize = 4;
+
+/// An aspect of a project that reviews score separately.
+#[derive(
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialOrd,
+	Ord,
+)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+pub enum RatingDimension {
+	/// Experience and track record of the team.
+	Team,
+	/// Supply, distribution and utility of the token.
+	Tokenomics,
+	/// Quality, testing and audits of the code.
+	CodeQuality,
+	/// Size and engagement of the community.
+	Community,
+}
+
+impl RatingDimension {
+	/// All dimensions, in the order of `DimensionScores`.
+	pub const ALL: [RatingDimension; NUMBER_OF_DIMENSIONS] = [
+		RatingDimension::Team,
+		RatingDimension::Tokenomics,
+		RatingDimension::CodeQuality,
+		RatingDimension::Community,
+	];
+	/// Position of the dimension in `DimensionScores`.
+	pub fn index(&self) -> usize {
+		*self as usize
+	}
+}
+
+/// A score per `RatingDimension`, in the order of `RatingDimension::ALL`.
+pub type DimensionScores = [u8; NUMBER_OF_DIMENSIONS];
+
+/// Whether the score is within `MIN_SCORE..=MAX_SCORE`.
+pub fn is_valid_score(score: u8) -> bool {
+	(MIN_SCORE..=MAX_SCORE).contains(&score)
+}
+
+/// Average ratings of a project over its accepted reviews, in hundredths of a point.
+/// All zero when the project has no accepted reviews.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+pub struct ProjectRating {
+	/// Average of the overall review scores.
+	pub overall: u32,
+	/// Average score per `RatingDimension`, in the order of `RatingDimension::ALL`.
+	pub dimensions: [u32; NUMBER_OF_DIMENSIONS],
+	/// Average of the overall review scores, weighted by each reviewer's rank points
+	/// when reviewing plus the helpfulness points of the review.
+	pub weighted_overall: u32,
+	/// The number of accepted reviews the averages are over.
+	pub number_of_reviews: u32,
+}
+
+/// How far the on-chain identity of an account has been verified.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+pub enum IdentityStatus {
+	/// The account has no identity.
+	None,
+	/// The account has an identity no registrar has vouched for.
+	Unverified,
+	/// A registrar judged the identity `Reasonable` or `KnownGood`, and none judged it erroneous.
+	Verified,
+}
+
+/// Source of identity judgements, such as `pallet_identity`.
+pub trait IdentityVerifier<AccountId> {
+	fn identity_status(who: &AccountId) -> IdentityStatus;
+	/// Give the account a `Verified` identity, so benchmarks can pass identity checks.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_verified(who: &AccountId);
+}
+
+/// No identity provider: every account is `IdentityStatus::None`.
+impl<AccountId> IdentityVerifier<AccountId> for () {
+	fn identity_status(_: &AccountId) -> IdentityStatus {
+		IdentityStatus::None
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_verified(_: &AccountId) {}
+}
+
+/// Source of accounts that act on behalf of others, such as `pallet_proxy` proxies or
+/// `pallet_identity` sub-accounts.
+pub trait AccountRelations<AccountId> {
+	/// Whether `who` is a proxy or sub-account of `principal`.
+	fn acts_for(who: &AccountId, principal: &AccountId) -> bool;
+}
+
+/// No relations: every account only acts for itself.
+impl<AccountId> AccountRelations<AccountId> for () {
+	fn acts_for(_: &AccountId, _: &AccountId) -> bool {
+		false
+	}
+}
+
 /// The metadata of a project.
 type MetaData<StringLen> = BoundedVec<u8, StringLen>;
 
+/// Maximum length of a project name, in bytes.
+pub const MAX_NAME_LENGTH: usize = 64;
+/// Maximum length of a project website, in bytes.
+pub const MAX_WEBSITE_LENGTH: usize = 256;
+/// Maximum length of a project category, in bytes.
+pub const MAX_CATEGORY_LENGTH: usize = 32;
+/// Maximum length of a token symbol, in bytes.
+pub const MAX_SYMBOL_LENGTH: usize = 12;
+
+/// The on-chain profile of a project. Lets the runtime filter and index projects
+/// without looking up the metadata off-chain.
+#[derive(
+	Encode,
+	Decode,
+	Default,
+	Eq,
+	PartialEq,
+	Clone,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialOrd,
+	Ord,
+)]
+pub struct ProjectProfile<StringLen>
+where
+	StringLen: Get<u32>,
+{
+	/// Display name. utf-8, at most `MAX_NAME_LENGTH` bytes.
+	pub name: BoundedVec<u8, StringLen>,
+	/// Homepage, an http(s) url of at most `MAX_WEBSITE_LENGTH` bytes.
+	pub website: BoundedVec<u8, StringLen>,
+	/// Category such as `defi` or `wallet`. Lowercase ascii letters, digits and dashes.
+	pub category: BoundedVec<u8, StringLen>,
+	/// Symbol of the project's token, if it has one. Uppercase ascii letters and digits.
+	pub token_symbol: Option<BoundedVec<u8, StringLen>>,
+	/// Para id of the chain the project lives on, if it is a parachain.
+	pub para_id: Option<u32>,
+	/// CID of the extended metadata.
+	pub metadata: MetaData<StringLen>,
+}
+
+/// Reasons a project profile can be rejected.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+pub enum ProfileError {
+	/// Name is empty, too long, not utf-8 or contains control characters.
+	InvalidName,
+	/// Website is too long or not an http(s) url.
+	InvalidWebsite,
+	/// Category is empty, too long or has characters outside `a-z`, `0-9` and `-`.
+	InvalidCategory,
+	/// Token symbol is empty, too long or has characters outside `A-Z` and `0-9`.
+	InvalidTokenSymbol,
+}
+
+impl<StringLen> ProjectProfile<StringLen>
+where
+	StringLen: Get<u32>,
+{
+	/// Check every field of the profile, returning the first invalid one.
+	pub fn validate(&self) -> Result<(), ProfileError> {
+		let name_ok = !self.name.is_empty() &&
+			self.name.len() <= MAX_NAME_LENGTH &&
+			sp_std::str::from_utf8(&self.name)
+				.map(|name| !name.chars().any(char::is_control))
+				.unwrap_or(false);
+		if !name_ok {
+			return Err(ProfileError::InvalidName)
+		}
+		if !is_valid_website(&self.website) {
+			return Err(ProfileError::InvalidWebsite)
+		}
+		if !is_valid_label(&self.category) {
+			return Err(ProfileError::InvalidCategory)
+		}
+		if let Some(symbol) = &self.token_symbol {
+			let symbol_ok = !symbol.is_empty() &&
+				symbol.len() <= MAX_SYMBOL_LENGTH &&
+				symbol.iter().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+			if !symbol_ok {
+				return Err(ProfileError::InvalidTokenSymbol)
+			}
+		}
+		Ok(())
+	}
+}
+
+/// A category or tag: 1 to `MAX_CATEGORY_LENGTH` lowercase ascii letters, digits and dashes.
+pub fn is_valid_label(label: &[u8]) -> bool {
+	!label.is_empty() &&
+		label.len() <= MAX_CATEGORY_LENGTH &&
+		label.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-')
+}
+
+/// An http(s) url with a host and no whitespace or non-ascii characters.
+fn is_valid_website(website: &[u8]) -> bool {
+	if website.len() > MAX_WEBSITE_LENGTH {
+		return false
+	}
+	let rest = match website {
+		[b'h', b't', b't', b'p', b's', b':', b'/', b'/', rest @ ..] => rest,
+		[b'h', b't', b't', b'p', b':', b'/', b'/', rest @ ..] => rest,
+		_ => return false,
+	};
+	let host = rest.split(|c| *c == b'/').next().unwrap_or_default();
+	!host.is_empty() && rest.iter().all(|c| c.is_ascii_graphic())
+}
+
 
 /// The status of the proposal
 #[derive(
@@ -48,6 +286,10 @@ pub enum Status {
 	Proposed,
 	/// Proposal accepted
 	Accepted,
+	/// Wound down by its owner. Can no longer be reviewed.
+	Closed,
+	/// Proposal rejected, see the reason for why.
+	Rejected,
 }
 /// Reason for the current status - Required for rejected proposal.
 #[derive(
@@ -126,20 +368,34 @@ where
 	pub owner_id: UserID,
 	/// A bool that allows for simple allocation of the unique chocolate badge. NFT?? (default: false)
 	badge: Option<bool>,
-	/// Project metadata - req - default some .
-	metadata: MetaData<StringLen>,
+	/// Project profile, holding the metadata - req - default some .
+	profile: ProjectProfile<StringLen>,
+	/// Number of times the metadata has been updated since creation. Starts at 0.
+	pub metadata_version: u32,
 	/// the status of the project's proposal in the council - default proposed.
 	pub proposal_status: ProposalStatus<StringLen>,
 	/// A reward value for the project.
 	/// Users are rewarded in native currency
 	/// Todo: Remove reward amount tracking here and simplify reward logic by using constant value
 	pub reward: Balance,
+	/// The existential deposit reserved along with the reward. Together with `reward`, it is
+	/// what the owner has reserved for the project, apart from any other reserves they hold.
+	/// Released along with the reward when the project is closed.
+	pub reserve_top_up: Balance,
 	/// A sum of all the points of users who wrote a review for the project. Saturate when u32::MAX.
 	pub total_user_scores: u32,
 	/// The total review scores for a project
 	pub total_review_score: u64,
+	/// The total review scores per `RatingDimension`
+	pub dimension_totals: [u64; NUMBER_OF_DIMENSIONS],
+	/// The sum of review scores times their rating weight
+	pub weighted_score_total: u64,
+	/// The sum of the rating weights of accepted reviews
+	pub total_rating_weight: u64,
 	/// The number of reviews submitted
 	pub number_of_reviews: u32,
+	/// The number of reviews awaiting judgement
+	pub pending_reviews: u32,
 }
 
 impl<UserID, Balance, StringLen> Project<UserID, Balance, StringLen>
@@ -148,23 +404,214 @@ where
 	StringLen: Get<u32>,
 {
 	///  Set useful defaults.
-	///  Initialises a project with defaults on everything except id and metadata
-	pub fn new(owner_id: UserID, metadata: MetaData<StringLen>) -> Self {
+	///  Initialises a project with defaults on everything except id and profile
+	pub fn new(owner_id: UserID, profile: ProjectProfile<StringLen>) -> Self {
 		Project {
 			owner_id,
 			badge: Option::None,
-			metadata,
+			profile,
+			metadata_version: Zero::zero(),
 			reward: Zero::zero(),
+			reserve_top_up: Zero::zero(),
 			proposal_status: ProposalStatus {
 				status: Default::default(),
 				reason: Default::default(),
 			},
 			total_user_scores: Zero::zero(),
 			number_of_reviews: Zero::zero(),
+			pending_reviews: Zero::zero(),
 			total_review_score: Zero::zero(),
+			dimension_totals: Default::default(),
+			weighted_score_total: Zero::zero(),
+			total_rating_weight: Zero::zero(),
+		}
+	}
+	/// The profile of the project.
+	pub fn profile(&self) -> &ProjectProfile<StringLen> {
+		&self.profile
+	}
+	/// The current metadata of the project.
+	pub fn metadata(&self) -> &MetaData<StringLen> {
+		&self.profile.metadata
+	}
+	/// Add the scores of an accepted review to the project's totals, with the given rating weight.
+	pub fn add_scores(&mut self, review_score: u8, dimension_scores: &DimensionScores, weight: u32) {
+		self.number_of_reviews = self.number_of_reviews.saturating_add(1);
+		self.total_review_score = self.total_review_score.saturating_add(u64::from(review_score));
+		for (total, score) in self.dimension_totals.iter_mut().zip(dimension_scores) {
+			*total = total.saturating_add(u64::from(*score));
+		}
+		self.add_rating_weight(review_score, weight);
+	}
+	/// Remove the scores of a superseded review from the project's totals, with its rating weight.
+	pub fn remove_scores(&mut self, review_score: u8, dimension_scores: &DimensionScores, weight: u32) {
+		self.number_of_reviews = self.number_of_reviews.saturating_sub(1);
+		self.total_review_score = self.total_review_score.saturating_sub(u64::from(review_score));
+		for (total, score) in self.dimension_totals.iter_mut().zip(dimension_scores) {
+			*total = total.saturating_sub(u64::from(*score));
 		}
+		self.remove_rating_weight(review_score, weight);
+	}
+	/// Increase the rating weight of an accepted review.
+	pub fn add_rating_weight(&mut self, review_score: u8, weight: u32) {
+		let weighted_score = u64::from(review_score).saturating_mul(u64::from(weight));
+		self.weighted_score_total = self.weighted_score_total.saturating_add(weighted_score);
+		self.total_rating_weight = self.total_rating_weight.saturating_add(u64::from(weight));
+	}
+	/// Decrease the rating weight of an accepted review.
+	pub fn remove_rating_weight(&mut self, review_score: u8, weight: u32) {
+		let weighted_score = u64::from(review_score).saturating_mul(u64::from(weight));
+		self.weighted_score_total = self.weighted_score_total.saturating_sub(weighted_score);
+		self.total_rating_weight = self.total_rating_weight.saturating_sub(u64::from(weight));
+	}
+	/// The project's average ratings.
+	pub fn rating(&self) -> ProjectRating {
+		let average = |total: u64| -> u32 {
+			match self.number_of_reviews {
+				0 => 0,
+				n => (total.saturating_mul(100) / u64::from(n)).try_into().unwrap_or(u32::MAX),
+			}
+		};
+		let mut dimensions = [0; NUMBER_OF_DIMENSIONS];
+		for (average_score, total) in dimensions.iter_mut().zip(self.dimension_totals) {
+			*average_score = average(total);
+		}
+		let weighted_overall = match self.total_rating_weight {
+			0 => 0,
+			weight => (self.weighted_score_total.saturating_mul(100) / weight)
+				.try_into()
+				.unwrap_or(u32::MAX),
+		};
+		ProjectRating {
+			overall: average(self.total_review_score),
+			dimensions,
+			weighted_overall,
+			number_of_reviews: self.number_of_reviews,
+		}
+	}
+	/// Replace the project's metadata and bump its version.
+	/// Returns the previous metadata so callers can keep a history.
+	pub fn set_metadata(&mut self, metadata: MetaData<StringLen>) -> MetaData<StringLen> {
+		self.metadata_version = self.metadata_version.saturating_add(1);
+		sp_std::mem::replace(&mut self.profile.metadata, metadata)
+	}
+}
+/// The role of a team member on a project. The owner implicitly holds every role.
+#[derive(
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialOrd,
+	Ord,
+)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+pub enum ProjectRole {
+	/// Manages the team and can act as both maintainer and responder.
+	Admin,
+	/// Updates metadata and tops up the reward.
+	Maintainer,
+	/// Replies to reviews.
+	Responder,
+}
+
+impl ProjectRole {
+	/// Can add and remove team members.
+	pub fn can_manage_team(&self) -> bool {
+		matches!(self, ProjectRole::Admin)
+	}
+	/// Can update the project's metadata.
+	pub fn can_update_metadata(&self) -> bool {
+		matches!(self, ProjectRole::Admin | ProjectRole::Maintainer)
+	}
+	/// Can top up the project's reward.
+	pub fn can_fund(&self) -> bool {
+		matches!(self, ProjectRole::Admin | ProjectRole::Maintainer)
+	}
+	/// Can reply to reviews on behalf of the project.
+	pub fn can_respond(&self) -> bool {
+		matches!(self, ProjectRole::Admin | ProjectRole::Responder)
+	}
+}
+
+/// A commit-reveal review round. While a round is open, reviews are committed as hashes
+/// until `commit_end`, then revealed until `reveal_end`.
+#[derive(
+	Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialOrd, Ord,
+)]
+pub struct ReviewRound<BlockNumber> {
+	/// Last block reviews can be committed in.
+	pub commit_end: BlockNumber,
+	/// Last block reviews can be revealed in.
+	pub reveal_end: BlockNumber,
+}
+
+impl<BlockNumber: PartialOrd> ReviewRound<BlockNumber> {
+	/// Whether reviews can be committed at `now`.
+	pub fn is_commit_phase(&self, now: &BlockNumber) -> bool {
+		now <= &self.commit_end
 	}
+	/// Whether reviews can be revealed at `now`.
+	pub fn is_reveal_phase(&self, now: &BlockNumber) -> bool {
+		now > &self.commit_end && now <= &self.reveal_end
+	}
+	/// Whether the round is still open at `now`.
+	pub fn is_open(&self, now: &BlockNumber) -> bool {
+		now <= &self.reveal_end
+	}
+}
+
+/// A review committed as a hash of its reviewer, project, score, dimension scores, content
+/// and a salt.
+#[derive(
+	Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialOrd, Ord,
+)]
+pub struct ReviewCommit<Hash, CurrencyIdAlias, BlockNumber> {
+	pub commitment: Hash,
+	/// Currency the user provided for collateral
+	pub collateral_currency_id: CurrencyIdAlias,
+	/// The round the review was committed in.
+	pub round: ReviewRound<BlockNumber>,
+}
+
+/// Content on chain that points to a CID.
+#[derive(
+	Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialOrd, Ord,
+)]
+pub enum ContentRef {
+	/// The metadata of a project.
+	Project(ProjectID),
+	/// The content of a review.
+	Review(ReviewID),
 }
+
+/// Why content was flagged for moderators.
+#[derive(
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialOrd,
+	Ord,
+)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+pub enum FlagReason {
+	/// The CID could not be fetched from the gateway.
+	Unavailable,
+	/// The document is not json matching the expected schema.
+	Malformed,
+}
+
 /// A trait that allows project to:
 /// - reserve some token for rewarding its reviewers.
 pub trait ProjectIO<T: Config> {
@@ -174,7 +621,7 @@ pub trait ProjectIO<T: Config> {
 	/// Performs the necessary checks on the project's side to ensure that they can reward the user
 	/// At this instance
 	///
-	/// - checks if the project's advertised reward is same as reserved balance
+	/// - checks if the owner's reserved balance covers the project's tracked reward reserve
 	/// - checks if the project has enough free balance to safely transfer reward after release to the user
 	fn check_reward(
 		project: &Project<Self::UserID, Self::Balance, Self::StringLimit>,
@@ -182,7 +629,8 @@ pub trait ProjectIO<T: Config> {
 	/// Check if the project owner can offer up reward amount when intialising.
 	fn can_reward(project: &Self::UserID) -> bool;
 	/// Reserve an initial amount for use as reward
-	/// Reserve some reward and possibly + liveness req as we initialise the project
+	/// Reserve the reward cap and the existential deposit as we initialise the project, and
+	/// track both on the project
 	/// # Fallible
 	/// does no checks for ability to reserve.
 	/// (When safe, move from mut to immut)
@@ -197,4 +645,9 @@ pub trait ProjectIO<T: Config> {
 		project: &mut Project<Self::UserID, Self::Balance, Self::StringLimit>,
 		amount: Self::Balance,
 	) -> DispatchResult;
+	/// Release what is left of the reward, along with the existential top-up, back to the owner.
+	/// Returns the amount that could not be unreserved.
+	fn release_reward(
+		project: &mut Project<Self::UserID, Self::Balance, Self::StringLimit>,
+	) -> Self::Balance;
 }
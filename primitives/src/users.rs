@@ -1,4 +1,6 @@
 use super::*;
+use sp_core::H160;
+use sp_std::vec::Vec;
 
 #[derive(
 	Encode,
@@ -15,13 +17,106 @@ use super::*;
 pub struct User {
 	pub rank_points: u32,
 	pub project_id: Option<u32>,
+	/// Reviews by the user that have not been judged yet, counting unrevealed review commits.
+	/// A user can only deregister at zero.
+	pub open_reviews: u32,
 }
 impl Default for User{
 	fn default() -> Self{
 		// Start from 1 because of total project score calc to avoid accidentally recording zero when we use Default::default()
-		User { rank_points: 1, project_id: Option::None }
+		User { rank_points: 1, project_id: Option::None, open_reviews: 0 }
 	}
 }
+/// Minimum length of a username, in bytes.
+pub const MIN_USERNAME_LENGTH: usize = 3;
+/// Maximum length of a username, in bytes.
+pub const MAX_USERNAME_LENGTH: usize = 32;
+/// Maximum length of a user's display name, in bytes.
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 64;
+
+/// The public profile of a user, so reviews can be attributed to more than an account id.
+/// The avatar and bio live off-chain behind their CIDs.
+#[derive(
+	Encode,
+	Decode,
+	Default,
+	Eq,
+	PartialEq,
+	Clone,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialOrd,
+	Ord,
+)]
+pub struct UserProfile<StringLen>
+where
+	StringLen: Get<u32>,
+{
+	/// Unique handle, stored normalised by `normalise_username`.
+	pub username: BoundedVec<u8, StringLen>,
+	/// Display name. utf-8, at most `MAX_DISPLAY_NAME_LENGTH` bytes.
+	pub display_name: BoundedVec<u8, StringLen>,
+	/// CID of the avatar image, if any.
+	pub avatar: Option<BoundedVec<u8, StringLen>>,
+	/// CID of the bio, if any.
+	pub bio: Option<BoundedVec<u8, StringLen>>,
+}
+
+/// Reasons a user profile can be rejected.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+pub enum UserProfileError {
+	/// Username is too short, too long or has characters outside `a-z`, `0-9` and `_`.
+	InvalidUsername,
+	/// Display name is empty, too long, not utf-8 or contains control characters.
+	InvalidDisplayName,
+	/// Avatar is not a valid CID.
+	InvalidAvatar,
+	/// Bio is not a valid CID.
+	InvalidBio,
+}
+
+impl<StringLen> UserProfile<StringLen>
+where
+	StringLen: Get<u32>,
+{
+	/// Check every field of the profile, returning the first invalid one.
+	/// The username must already be normalised.
+	pub fn validate(&self) -> Result<(), UserProfileError> {
+		if normalise_username(&self.username).as_deref() != Some(&self.username[..]) {
+			return Err(UserProfileError::InvalidUsername)
+		}
+		let display_name_ok = !self.display_name.is_empty() &&
+			self.display_name.len() <= MAX_DISPLAY_NAME_LENGTH &&
+			sp_std::str::from_utf8(&self.display_name)
+				.map(|name| !name.chars().any(char::is_control))
+				.unwrap_or(false);
+		if !display_name_ok {
+			return Err(UserProfileError::InvalidDisplayName)
+		}
+		if let Some(avatar) = &self.avatar {
+			crate::cid::validate(avatar).map_err(|_| UserProfileError::InvalidAvatar)?;
+		}
+		if let Some(bio) = &self.bio {
+			crate::cid::validate(bio).map_err(|_| UserProfileError::InvalidBio)?;
+		}
+		Ok(())
+	}
+}
+
+/// Lowercase a username, so `Alice` and `alice` claim the same entry in the registry.
+/// None if the result isn't `MIN_USERNAME_LENGTH` to `MAX_USERNAME_LENGTH` of `a-z`, `0-9` and `_`.
+pub fn normalise_username(username: &[u8]) -> Option<Vec<u8>> {
+	if username.len() < MIN_USERNAME_LENGTH || username.len() > MAX_USERNAME_LENGTH {
+		return None
+	}
+	let normalised = username.to_ascii_lowercase();
+	normalised
+		.iter()
+		.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'_')
+		.then(|| normalised)
+}
+
 /// UserIO trait for CRUD on users store
 pub trait UserIO<T: Config> {
 	fn get_user_by_id(id: &T::AccountId) -> Option<User>;
@@ -29,8 +124,18 @@ pub trait UserIO<T: Config> {
 	/// Allows us to check if the user even exists before calling get by id.
 	fn check_user_exists(id: &T::AccountId) -> bool;
 	/// Checks if the user exists, else creates a new user with wanted defaults.
-	fn get_or_create_default(id: &T::AccountId) -> User;
-	/// Idempotent. Simply creates item in storage if it  doesn't already exist. Use update_user if you'd like to mutate the user after knowing it's been created
-	fn set_user(id: &T::AccountId, user: User) -> ();
+	/// Creating a user reserves the registration deposit, so this fails if it can't be paid.
+	fn get_or_create_default(id: &T::AccountId) -> Result<User, DispatchError>;
+	/// Idempotent. Simply creates item in storage if it  doesn't already exist, reserving the registration deposit. Use update_user if you'd like to mutate the user after knowing it's been created
+	fn set_user(id: &T::AccountId, user: User) -> DispatchResult;
 	fn update_user(id: &T::AccountId, user: User) -> DispatchResult;
+	/// The normalised username of the user, if they have set a profile.
+	fn username_of(id: &T::AccountId) -> Option<Vec<u8>>;
+	/// The account holding a username. The lookup is case-insensitive.
+	fn account_of_username(username: &[u8]) -> Option<T::AccountId>;
+	/// The Ethereum address the user proved they control, if any.
+	fn eth_address_of(id: &T::AccountId) -> Option<H160>;
+	/// Punish the user for a malicious contribution, cutting their rank points and slashing
+	/// the bond of whoever vouched for them.
+	fn penalise(id: &T::AccountId) -> DispatchResult;
 }
@@ -20,9 +20,15 @@ use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, Verify},
-	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature, Percent,
+	traits::{
+		AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, DispatchInfoOf,
+		Extrinsic as ExtrinsicT, IdentifyAccount, PostDispatchInfoOf, SignedExtension,
+		StaticLookup, Verify,
+	},
+	transaction_validity::{
+		TransactionPriority, TransactionSource, TransactionValidity, TransactionValidityError,
+	},
+	ApplyExtrinsicResult, DispatchResult, MultiSignature, Percent, SaturatedConversion,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -59,7 +65,9 @@ use polkadot_runtime_common::{impls::DealWithFees, BlockHashCount, SlowAdjusting
 use weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight};
 
 // Orml imports
+use chocolate_primitives::projects::{AccountRelations, IdentityStatus, IdentityVerifier};
 use orml_currencies::BasicCurrencyAdapter;
+use pallet_identity::Judgement;
 use orml_traits::parameter_type_with_key;
 
 // XCM Imports
@@ -113,6 +121,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	NoteOwnerTransfers,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -128,8 +137,16 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations run on runtime upgrade, in order. Users migrate before the chocolate pallet,
+/// which counts their open reviews.
+pub type Migrations = (
+	pallet_users::migrations::v1::MigrateToV1<Runtime>,
+	pallet_chocolate::migrations::Migrations<Runtime>,
+);
+
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
 /// node's balance type.
 ///
@@ -232,10 +249,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("template-parachain"),
 	impl_name: create_runtime_str!("template-parachain"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 10,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -407,7 +424,7 @@ impl pallet_balances::Config for Runtime {
 	type DustRemoval = ();
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
-	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
+	type WeightInfo = WithNoteTransfer<pallet_balances::weights::SubstrateWeight<Runtime>>;
 	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
 }
@@ -428,6 +445,20 @@ impl pallet_transaction_payment::Config for Runtime {
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 }
 
+parameter_types! {
+	pub const MinVestedTransfer: Balance = 100 * UNIT;
+}
+
+/// Vesting schedules, used to lock tokens minted to token-sale buyers and grant recipients.
+impl pallet_vesting::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = MinVestedTransfer;
+	type WeightInfo = WithNoteTransfer<pallet_vesting::weights::SubstrateWeight<Runtime>>;
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
 parameter_types! {
 	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT / 4;
 	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT / 4;
@@ -560,13 +591,227 @@ impl orml_currencies::Config for Runtime {
 	type MultiCurrency = Tokens;
 	type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
-	type WeightInfo = ();
+	type WeightInfo = WithNoteTransfer<()>;
 }
 
 parameter_types! {
 	pub const RewardCap: Balance = 50 * HECTOUNIT;
 	pub const UserCollateral: Balance = 10 * HECTOUNIT;
+	pub const MaxMetadataHistory: u32 = 10;
+	pub const MaxTeamMembers: u32 = 20;
+	pub const MaxTags: u32 = 10;
+	pub const ReviewVoteDeposit: Balance = UNIT;
+	pub const VotesPerRankPoint: u32 = 5;
+	pub const MaxReviewEdits: u32 = 3;
+	pub const BenchmarkCollateralCurrencyId: CurrencyId = CurrencyId::DOT;
+	pub const MaxRoundPeriod: BlockNumber = 7 * DAYS;
+	pub const TransferWindow: BlockNumber = 30 * DAYS;
+	pub const ChocolateUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
 }
+/// Identity judgements from pallet-identity, for the `RequireIdentity` policy of pallet-chocolate.
+pub struct IdentityJudgements;
+impl IdentityVerifier<AccountId> for IdentityJudgements {
+	fn identity_status(who: &AccountId) -> IdentityStatus {
+		let registration = match Identity::identity(who) {
+			Some(registration) => registration,
+			None => return IdentityStatus::None,
+		};
+		let judgements = registration.judgements.iter().map(|(_, judgement)| judgement);
+		let erroneous = judgements.clone().any(|judgement| *judgement == Judgement::Erroneous);
+		let good = judgements
+			.any(|judgement| matches!(judgement, Judgement::Reasonable | Judgement::KnownGood));
+		if good && !erroneous {
+			IdentityStatus::Verified
+		} else {
+			IdentityStatus::Unverified
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_verified(who: &AccountId) {
+		use frame_support::traits::Currency;
+		let registrar: AccountId = frame_benchmarking::account("registrar", 0, 0);
+		Balances::make_free_balance_be(who, 100 * HECTOUNIT);
+		Identity::add_registrar(Origin::root(), registrar.clone().into())
+			.expect("Root can add registrars");
+		let index = Identity::registrars().len().saturating_sub(1) as u32;
+		let info = pallet_identity::IdentityInfo {
+			additional: Default::default(),
+			display: pallet_identity::Data::None,
+			legal: pallet_identity::Data::None,
+			web: pallet_identity::Data::None,
+			riot: pallet_identity::Data::None,
+			email: pallet_identity::Data::None,
+			pgp_fingerprint: None,
+			image: pallet_identity::Data::None,
+			twitter: pallet_identity::Data::None,
+		};
+		Identity::set_identity(Origin::signed(who.clone()), Box::new(info))
+			.expect("Funded account can set an identity");
+		Identity::provide_judgement(
+			Origin::signed(registrar),
+			index,
+			who.clone().into(),
+			Judgement::Reasonable,
+		)
+		.expect("Registrar can judge");
+	}
+}
+
+/// Proxies from pallet-proxy and sub-accounts from pallet-identity, for the conflict-of-interest
+/// checks of pallet-chocolate on reviews.
+pub struct ProxiesAndSubAccounts;
+impl AccountRelations<AccountId> for ProxiesAndSubAccounts {
+	fn acts_for(who: &AccountId, principal: &AccountId) -> bool {
+		let is_sub_account =
+			Identity::super_of(who).map_or(false, |(parent, _)| parent == *principal);
+		let is_proxy = Proxy::proxies(principal).0.iter().any(|proxy| proxy.delegate == *who);
+		is_sub_account || is_proxy
+	}
+}
+
+/// Notes the native, multi-currency and vested transfers of signed extrinsics in
+/// pallet-chocolate, so accounts recently funded by a project owner, before or after they took on
+/// the project, cannot review the owner's projects. Transfers made through a proxy are noted
+/// against the proxied account, even when the proxied call fails. Funds passed on through an
+/// intermediary are not seen.
+///
+/// The noted calls are charged for the note through `WithNoteTransfer`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, TypeInfo, RuntimeDebug)]
+pub struct NoteOwnerTransfers;
+impl NoteOwnerTransfers {
+	/// The sender and recipient of the transfer `who` makes by dispatching `call`, if any.
+	fn transfer_of(who: &AccountId, call: &Call) -> Option<(AccountId, AccountId)> {
+		let dest = match call {
+			Call::Balances(pallet_balances::Call::transfer { dest, .. }) |
+			Call::Balances(pallet_balances::Call::transfer_keep_alive { dest, .. }) |
+			Call::Balances(pallet_balances::Call::transfer_all { dest, .. }) |
+			Call::Currencies(orml_currencies::Call::transfer { dest, .. }) |
+			Call::Currencies(orml_currencies::Call::transfer_native_currency { dest, .. }) |
+			Call::Vesting(pallet_vesting::Call::vested_transfer { target: dest, .. }) => dest,
+			Call::Proxy(pallet_proxy::Call::proxy { real, call, .. }) |
+			Call::Proxy(pallet_proxy::Call::proxy_announced { real, call, .. }) => {
+				let real = <Runtime as frame_system::Config>::Lookup::lookup(real.clone()).ok()?;
+				return Self::transfer_of(&real, call)
+			},
+			_ => return None,
+		};
+		let recipient = <Runtime as frame_system::Config>::Lookup::lookup(dest.clone()).ok()?;
+		Some((who.clone(), recipient))
+	}
+}
+impl SignedExtension for NoteOwnerTransfers {
+	const IDENTIFIER: &'static str = "NoteOwnerTransfers";
+	type AccountId = AccountId;
+	type Call = Call;
+	type AdditionalSigned = ();
+	/// The sender and recipient of a transfer.
+	type Pre = Option<(AccountId, AccountId)>;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &AccountId,
+		call: &Call,
+		_info: &DispatchInfoOf<Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(Self::transfer_of(who, call))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_info: &DispatchInfoOf<Call>,
+		_post_info: &PostDispatchInfoOf<Call>,
+		_len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if let (Some(Some((sender, recipient))), Ok(())) = (pre, result) {
+			ChocolateModule::note_transfer(&sender, &recipient);
+		}
+		Ok(())
+	}
+}
+
+/// The weights of `W`, with the cost of `NoteOwnerTransfers` noting the transfer added to the
+/// calls it notes.
+pub struct WithNoteTransfer<W>(sp_std::marker::PhantomData<W>);
+impl<W> WithNoteTransfer<W> {
+	fn note() -> Weight {
+		use pallet_chocolate::WeightInfo;
+		pallet_chocolate::weights::SubstrateWeight::<Runtime>::note_transfer()
+	}
+}
+impl<W: pallet_balances::WeightInfo> pallet_balances::WeightInfo for WithNoteTransfer<W> {
+	fn transfer() -> Weight {
+		W::transfer().saturating_add(Self::note())
+	}
+	fn transfer_keep_alive() -> Weight {
+		W::transfer_keep_alive().saturating_add(Self::note())
+	}
+	fn set_balance_creating() -> Weight {
+		W::set_balance_creating()
+	}
+	fn set_balance_killing() -> Weight {
+		W::set_balance_killing()
+	}
+	fn force_transfer() -> Weight {
+		W::force_transfer()
+	}
+	fn transfer_all() -> Weight {
+		W::transfer_all().saturating_add(Self::note())
+	}
+	fn force_unreserve() -> Weight {
+		W::force_unreserve()
+	}
+}
+impl<W: orml_currencies::WeightInfo> orml_currencies::WeightInfo for WithNoteTransfer<W> {
+	fn transfer_non_native_currency() -> Weight {
+		W::transfer_non_native_currency().saturating_add(Self::note())
+	}
+	fn transfer_native_currency() -> Weight {
+		W::transfer_native_currency().saturating_add(Self::note())
+	}
+	fn update_balance_non_native_currency() -> Weight {
+		W::update_balance_non_native_currency()
+	}
+	fn update_balance_native_currency_creating() -> Weight {
+		W::update_balance_native_currency_creating()
+	}
+	fn update_balance_native_currency_killing() -> Weight {
+		W::update_balance_native_currency_killing()
+	}
+}
+impl<W: pallet_vesting::WeightInfo> pallet_vesting::WeightInfo for WithNoteTransfer<W> {
+	fn vest_locked(l: u32, s: u32) -> Weight {
+		W::vest_locked(l, s)
+	}
+	fn vest_unlocked(l: u32, s: u32) -> Weight {
+		W::vest_unlocked(l, s)
+	}
+	fn vest_other_locked(l: u32, s: u32) -> Weight {
+		W::vest_other_locked(l, s)
+	}
+	fn vest_other_unlocked(l: u32, s: u32) -> Weight {
+		W::vest_other_unlocked(l, s)
+	}
+	fn vested_transfer(l: u32, s: u32) -> Weight {
+		W::vested_transfer(l, s).saturating_add(Self::note())
+	}
+	fn force_vested_transfer(l: u32, s: u32) -> Weight {
+		W::force_vested_transfer(l, s)
+	}
+	fn not_unlocking_merge_schedules(l: u32, s: u32) -> Weight {
+		W::not_unlocking_merge_schedules(l, s)
+	}
+	fn unlocking_merge_schedules(l: u32, s: u32) -> Weight {
+		W::unlocking_merge_schedules(l, s)
+	}
+}
+
 /// Configure the pallet-chocolate in pallets/chocolate.
 impl pallet_chocolate::Config for Runtime {
 	type Event = Event;
@@ -577,10 +822,95 @@ impl pallet_chocolate::Config for Runtime {
 	type UserCollateral = UserCollateral;
 	type StringLimit = StringLimit;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type MaxMetadataHistory = MaxMetadataHistory;
+	type MaxTeamMembers = MaxTeamMembers;
+	type MaxTags = MaxTags;
+	type VoteDeposit = ReviewVoteDeposit;
+	type VotesPerRankPoint = VotesPerRankPoint;
+	type MaxReviewEdits = MaxReviewEdits;
+	type DataDepositPerByte = DataDepositPerByte;
+	type MaxRoundPeriod = MaxRoundPeriod;
+	type IdentityVerifier = IdentityJudgements;
+	type AccountRelations = ProxiesAndSubAccounts;
+	type TransferWindow = TransferWindow;
+	type AuthorityId = pallet_chocolate::offchain::crypto::ContentVerifierId;
+	type UnsignedPriority = ChocolateUnsignedPriority;
+	type WeightInfo = pallet_chocolate::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkCollateralCurrencyId = BenchmarkCollateralCurrencyId;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Index,
+	) -> Option<(Call, <UncheckedExtrinsic as ExtrinsicT>::SignaturePayload)> {
+		let tip = 0;
+		// Mortal for half of the block hash count, rounded to a power of two.
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			NoteOwnerTransfers,
+		);
+		let raw_payload = generic::SignedPayload::new(call, extra)
+			.map_err(|e| log::warn!("Unable to create signed payload: {:?}", e))
+			.ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let address = <Runtime as frame_system::Config>::Lookup::unlookup(account);
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (address, signature, extra)))
+	}
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	Call: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = Call;
+}
+parameter_types! {
+	pub const RegistrationDeposit: Balance = 10 * UNIT;
+	pub const VouchBond: Balance = 1 * HECTOUNIT;
+	pub const MinVoucherRank: u32 = 10;
+	pub const VouchRankBoost: u32 = 2;
+	pub const PenaltyRankPoints: u32 = 5;
+	pub const VouchSlash: Perbill = Perbill::from_percent(50);
+	pub const LinkPrefix: &'static [u8] = b"Link to Chocolate account:";
 }
 /// Configure the pallet-users in pallets/users.
 impl pallet_users::Config for Runtime {
 	type Event = Event;
+	type Currency = Balances;
+	type StringLimit = StringLimit;
+	type RegistrationDeposit = RegistrationDeposit;
+	type DataDepositPerByte = DataDepositPerByte;
+	type VouchBond = VouchBond;
+	type MinVoucherRank = MinVoucherRank;
+	type VouchRankBoost = VouchRankBoost;
+	type PenaltyRankPoints = PenaltyRankPoints;
+	type VouchSlash = VouchSlash;
+	type LinkPrefix = LinkPrefix;
+	type WeightInfo = pallet_users::weights::SubstrateWeight<Runtime>;
 }
 
 // Configure the council and its features
@@ -687,11 +1017,96 @@ impl pallet_treasury::Config for Runtime {
 	type SpendOrigin = frame_support::traits::NeverEnsureOrigin<u128>;
 }
 
+parameter_types! {
+	// Well above the genesis endowments of the development chains.
+	pub const MaxIssuance: Balance = 100_000_000_000 * UNIT;
+	// Other currencies are capped near the supply of the asset they stand for. The development
+	// chains endow more than this, so minting them there fails.
+	// About 1.4 billion DOT, with room for inflation.
+	pub const DotSupplyCap: Balance = 2_000_000_000 * UNIT;
+	// About 15 million KSM, with room for inflation.
+	pub const KsmSupplyCap: Balance = 20_000_000 * UNIT;
+	// Bitcoin's fixed supply.
+	pub const BtcSupplyCap: Balance = 21_000_000 * UNIT;
+}
+
+parameter_type_with_key! {
+	// The native currency is only minted to the treasury and beneficiaries, under `MaxIssuance`.
+	pub SupplyCaps: |currency_id: CurrencyId| -> Balance {
+		match currency_id {
+			CurrencyId::Native => 0,
+			CurrencyId::DOT => DotSupplyCap::get(),
+			CurrencyId::KSM => KsmSupplyCap::get(),
+			CurrencyId::BTC => BtcSupplyCap::get(),
+		}
+	};
+}
+
 impl pallet_minting::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type TreasuryOutlet = Treasury;
 	type ApproveOrigin = ApproveOrigin;
+	type MaxIssuance = MaxIssuance;
+	type Vesting = Vesting;
+	type MultiCurrency = Tokens;
+	type SupplyCaps = SupplyCaps;
+	type WeightInfo = pallet_minting::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkCurrencyId = BenchmarkCollateralCurrencyId;
+}
+
+parameter_types! {
+	pub const BasicDeposit: Balance = 10 * UNIT;
+	pub const FieldDeposit: Balance = 1 * UNIT;
+	pub const SubAccountDeposit: Balance = 2 * UNIT;
+	pub const MaxSubAccounts: u32 = 100;
+	pub const MaxAdditionalFields: u32 = 100;
+	pub const MaxRegistrars: u32 = 20;
+}
+
+/// Registrars are appointed by governance, and verify the identities of project owners.
+impl pallet_identity::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type BasicDeposit = BasicDeposit;
+	type FieldDeposit = FieldDeposit;
+	type SubAccountDeposit = SubAccountDeposit;
+	type MaxSubAccounts = MaxSubAccounts;
+	type MaxAdditionalFields = MaxAdditionalFields;
+	type MaxRegistrars = MaxRegistrars;
+	type Slashed = Treasury;
+	type ForceOrigin = ApproveOrigin;
+	type RegistrarOrigin = ApproveOrigin;
+	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	// One storage item; key size 32, value size 8.
+	pub const ProxyDepositBase: Balance = deposit(1, 8);
+	// Additional storage item size of 33 bytes.
+	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const MaxProxies: u32 = 32;
+	// One storage item; key size 32, value size 16.
+	pub const AnnouncementDepositBase: Balance = deposit(1, 16);
+	pub const AnnouncementDepositFactor: Balance = deposit(0, 68);
+	pub const MaxPending: u32 = 32;
+}
+
+/// Proxies of any type: a proxy may dispatch every call of the account it acts for.
+impl pallet_proxy::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type ProxyType = ();
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = MaxProxies;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = MaxPending;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -712,6 +1127,7 @@ construct_runtime!(
 		// Monetary stuff.
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 10,
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>} = 11,
+		Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>, Config<T>} = 12,
 
 		// Collator support. The order of these 4 are important and shall not change.
 		Authorship: pallet_authorship::{Pallet, Call, Storage} = 20,
@@ -729,7 +1145,7 @@ construct_runtime!(
 		// Chocolate
 		TemplatePallet: pallet_template::{Pallet, Call, Storage, Event<T>}  = 40,
 		UsersModule: pallet_users::{Pallet, Call, Storage, Event<T>} =  41,
-		ChocolateModule: pallet_chocolate::{Pallet, Call, Config<T>, Storage, Event<T>} =  42,
+		ChocolateModule: pallet_chocolate::{Pallet, Call, Config<T>, Storage, Event<T>, ValidateUnsigned} =  42,
 		MintingModule: pallet_minting::{Pallet, Call, Config<T>, Storage, Event<T>} =  43,
 		// TREASURY
 		Council: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>}  = 50,
@@ -739,6 +1155,10 @@ construct_runtime!(
 		// Orml multitokens
 		Currencies: orml_currencies::{Pallet, Call} = 60,
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 61,
+
+		// IDENTITY
+		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 70,
+		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>} = 71,
 	}
 );
 
@@ -759,7 +1179,11 @@ mod benches {
 		[pallet_elections_phragmen, PhragmenElection]
 		[pallet_collective, Council]
 		[pallet_treasury, Treasury]
-		[pallet_minting, Minting]
+		[pallet_minting, MintingModule]
+		[pallet_users, UsersModule]
+		[pallet_identity, Identity]
+		[pallet_proxy, Proxy]
+		[pallet_vesting, Vesting]
 	);
 }
 
@@ -864,6 +1288,48 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_chocolate_runtime_api::ChocolateApi<Block> for Runtime {
+		fn projects_by_category(
+			category: Vec<u8>,
+			start_after: Option<pallet_chocolate_runtime_api::ProjectID>,
+			limit: u32,
+		) -> Vec<pallet_chocolate_runtime_api::ProjectID> {
+			ChocolateModule::projects_by_category(category, start_after, limit)
+		}
+
+		fn projects_by_tag(
+			tag: Vec<u8>,
+			start_after: Option<pallet_chocolate_runtime_api::ProjectID>,
+			limit: u32,
+		) -> Vec<pallet_chocolate_runtime_api::ProjectID> {
+			ChocolateModule::projects_by_tag(tag, start_after, limit)
+		}
+
+		fn projects_by_status(
+			status: pallet_chocolate_runtime_api::Status,
+			start_after: Option<pallet_chocolate_runtime_api::ProjectID>,
+			limit: u32,
+		) -> Vec<pallet_chocolate_runtime_api::ProjectID> {
+			ChocolateModule::projects_by_status(status, start_after, limit)
+		}
+
+		fn categories(start_after: Option<Vec<u8>>, limit: u32) -> Vec<Vec<u8>> {
+			ChocolateModule::categories(start_after, limit)
+		}
+
+		fn project_rating(
+			project_id: pallet_chocolate_runtime_api::ProjectID,
+		) -> Option<pallet_chocolate_runtime_api::ProjectRating> {
+			ChocolateModule::project_rating(project_id)
+		}
+
+		fn owner_identity(
+			project_id: pallet_chocolate_runtime_api::ProjectID,
+		) -> Option<pallet_chocolate_runtime_api::IdentityStatus> {
+			ChocolateModule::owner_identity(project_id)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
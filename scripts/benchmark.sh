@@ -0,0 +1,33 @@
+#!/bin/bash
+
+# Regenerate the weights of our pallets from their benchmarks.
+# Run from the repository root, on reference hardware: ./scripts/benchmark.sh [pallet...]
+set -e
+
+PALLETS=${@:-chocolate users minting}
+
+function build {
+    cargo build --release --features runtime-benchmarks
+}
+# Benchmark pallet_$1 and write its weights next to its benchmarks
+function benchmark {
+    ./target/release/parachain-collator benchmark pallet \
+    --chain=dev \
+    --execution=wasm \
+    --wasm-execution=compiled \
+    --pallet="pallet_$1" \
+    --extrinsic='*' \
+    --steps=50 \
+    --repeat=20 \
+    --template=./.maintain/frame-weight-template.hbs \
+    --output="./pallets/$1/src/weights.rs"
+}
+
+function main {
+    build
+    for pallet in $PALLETS; do
+        benchmark "$pallet"
+    done
+}
+
+main